@@ -2,12 +2,23 @@ use std::hint::black_box;
 
 use criterion::{Criterion, criterion_group, criterion_main};
 use life::prelude::*;
+use rand::{RngExt, SeedableRng, rngs::StdRng};
 
 fn load_beehive() -> World {
     let cells = Cells::try_from(Pattern::Glider.cells_str()).expect("require valid pattern");
     World::from(cells)
 }
 
+fn load_soup() -> World {
+    let mut rng = StdRng::seed_from_u64(0);
+    let cells = Cells::from_iter(
+        (0..256)
+            .flat_map(|r| (0..256).map(move |c| Cell::new(r, c)))
+            .filter(|_| rng.random::<f32>() < 0.3),
+    );
+    World::from(cells)
+}
+
 fn bench_next_generation(c: &mut Criterion) {
     let mut world = load_beehive();
 
@@ -38,5 +49,69 @@ fn bench_generations_iterator(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_next_generation, bench_generations_iterator);
+fn bench_dense_soup(c: &mut Criterion) {
+    let initial_world = load_soup();
+    let mut group = c.benchmark_group("dense_soup");
+    group.sample_size(10);
+
+    for algorithm in [Algorithm::Sets, Algorithm::Tiles] {
+        let mut world = initial_world.clone();
+        world.with_algorithm(&algorithm);
+        group.bench_function(format!("{:?}", algorithm).to_lowercase(), |b| {
+            b.iter(|| {
+                let mut world = world.clone();
+                (0..10).for_each(|_| world.next_generation());
+                black_box(world);
+            });
+        });
+    }
+
+    let mut tiles = Tiles::new(&Rule::default()).expect("supported rule");
+    group.bench_function("tiles_engine", |b| {
+        b.iter(|| {
+            tiles.with_cells(initial_world.live_cells());
+            tiles.step(10);
+            black_box(tiles.population());
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_parallel_step(c: &mut Criterion) {
+    let mut initial_world = load_soup();
+    initial_world.with_algorithm(&Algorithm::Sets);
+    let mut group = c.benchmark_group("parallel_step");
+    group.sample_size(10);
+
+    let available = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let mut thread_counts = vec![1];
+    if available > 1 {
+        thread_counts.push(available);
+    }
+
+    for threads in thread_counts {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("thread pool");
+        group.bench_function(format!("threads_{}", threads), |b| {
+            b.iter(|| {
+                let mut world = initial_world.clone();
+                pool.install(|| (0..10).for_each(|_| world.next_generation()));
+                black_box(world);
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_next_generation,
+    bench_generations_iterator,
+    bench_dense_soup,
+    bench_parallel_step
+);
 criterion_main!(benches);
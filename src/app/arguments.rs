@@ -2,7 +2,7 @@ use std::{ops::RangeInclusive, path::PathBuf};
 
 use clap::*;
 
-use crate::prelude::{Bounds, Pattern};
+use crate::prelude::{Algorithm, Bounds, Pattern, Rule, Topology};
 
 fn parse_range(s: &str) -> Result<RangeInclusive<isize>, String> {
     let min_max = s.split("..").collect::<Vec<_>>();
@@ -25,6 +25,18 @@ fn parse_bounds(s: &str) -> Result<Bounds, String> {
     Ok(Bounds::new(rows, columns))
 }
 
+fn parse_rule(s: &str) -> Result<Rule, String> {
+    s.parse::<Rule>().map_err(|e| e.to_string())
+}
+
+fn parse_algorithm(s: &str) -> Result<Algorithm, String> {
+    s.parse::<Algorithm>().map_err(|e| e.to_string())
+}
+
+fn parse_topology(s: &str) -> Result<Topology, String> {
+    s.parse::<Topology>().map_err(|e| e.to_string())
+}
+
 #[derive(Debug, Parser)]
 #[command(
     name = env!("CARGO_PKG_NAME"),
@@ -42,6 +54,47 @@ pub struct Arguments {
     /// The initial world boundary "minRow..maxRow,minColumn..maxColumn" (default is unbounded)
     #[arg(long, value_parser = parse_bounds)]
     bounds: Option<Bounds>,
+
+    /// The rule, such as "B36/S23", "23/36" or "b3s23" (default is the source's rule, or B3/S23)
+    #[arg(long, value_parser = parse_rule)]
+    rule: Option<Rule>,
+
+    /// How the edges of a bounded world are joined, "plane", "torus", "klein-bottle",
+    /// "cross-surface" or "sphere" (default is the source's topology, or plane)
+    #[arg(long, value_parser = parse_topology)]
+    topology: Option<Topology>,
+
+    /// The algorithm computing the generations, "auto", "sets", "hashlife" or "tiles" (default is
+    /// auto, choosing tiles for dense soups and sets otherwise)
+    #[arg(long, value_parser = parse_algorithm)]
+    algorithm: Option<Algorithm>,
+
+    /// The generation at which to start displaying, jumping straight there (default is 0)
+    #[arg(long)]
+    start: Option<u64>,
+
+    /// The generation after which to stop (default is when a generation repeats, or 10000 for
+    /// each soup of a census)
+    #[arg(long)]
+    generations: Option<u64>,
+
+    /// Take a census of the objects left by this many random soups of B3/S23, instead of
+    /// displaying a world
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "world", "pattern", "viewport", "bounds", "rule", "topology", "algorithm", "start",
+        ],
+    )]
+    census: Option<u64>,
+
+    /// The seed of the first soup of a census (default is random)
+    #[arg(long, requires = "census")]
+    seed: Option<u64>,
+
+    /// The file the census report is written to (default is census.txt)
+    #[arg(long, requires = "census")]
+    report: Option<PathBuf>,
 }
 
 #[derive(Clone, Debug, Args)]
@@ -72,6 +125,38 @@ impl Arguments {
     pub fn bounds(&self) -> Option<&Bounds> {
         self.bounds.as_ref()
     }
+
+    pub fn rule(&self) -> Option<&Rule> {
+        self.rule.as_ref()
+    }
+
+    pub fn topology(&self) -> Option<&Topology> {
+        self.topology.as_ref()
+    }
+
+    pub fn algorithm(&self) -> Option<&Algorithm> {
+        self.algorithm.as_ref()
+    }
+
+    pub fn start(&self) -> Option<&u64> {
+        self.start.as_ref()
+    }
+
+    pub fn generations(&self) -> Option<&u64> {
+        self.generations.as_ref()
+    }
+
+    pub fn census(&self) -> Option<&u64> {
+        self.census.as_ref()
+    }
+
+    pub fn seed(&self) -> Option<&u64> {
+        self.seed.as_ref()
+    }
+
+    pub fn report(&self) -> Option<&PathBuf> {
+        self.report.as_ref()
+    }
 }
 
 #[cfg(test)]
@@ -89,6 +174,14 @@ mod test {
         assert_eq!(args.source.pattern, None);
         assert_eq!(args.bounds, None);
         assert_eq!(args.viewport, None);
+        assert_eq!(args.rule, None);
+        assert_eq!(args.topology, None);
+        assert_eq!(args.algorithm, None);
+        assert_eq!(args.start, None);
+        assert_eq!(args.generations, None);
+        assert_eq!(args.census, None);
+        assert_eq!(args.seed, None);
+        assert_eq!(args.report, None);
     }
 
     #[test]
@@ -186,4 +279,106 @@ mod test {
         let error = Arguments::try_parse_from(args).expect_err("invalid args");
         assert!(matches!(error.kind(), ErrorKind::ValueValidation));
     }
+
+    #[test]
+    fn valid_rule() {
+        let args = "app --rule=B36/S23".split_whitespace();
+        let args = Arguments::try_parse_from(args).expect("valid args");
+        assert_eq!(args.rule, Some("B36/S23".parse().expect("valid rule")));
+    }
+
+    #[test]
+    fn valid_topology() {
+        let args = "app --topology=torus".split_whitespace();
+        let args = Arguments::try_parse_from(args).expect("valid args");
+        assert_eq!(args.topology, Some(Topology::Torus));
+    }
+
+    #[test]
+    fn invalid_topology() {
+        let args = "app --topology=mobius".split_whitespace();
+        let error = Arguments::try_parse_from(args).expect_err("invalid args");
+        assert!(matches!(error.kind(), ErrorKind::ValueValidation));
+    }
+
+    #[test]
+    fn valid_algorithm() {
+        let args = "app --algorithm=hashlife".split_whitespace();
+        let args = Arguments::try_parse_from(args).expect("valid args");
+        assert_eq!(args.algorithm, Some(Algorithm::HashLife));
+    }
+
+    #[test]
+    fn valid_tiles_algorithm() {
+        let args = "app --algorithm=tiles".split_whitespace();
+        let args = Arguments::try_parse_from(args).expect("valid args");
+        assert_eq!(args.algorithm, Some(Algorithm::Tiles));
+    }
+
+    #[test]
+    fn invalid_algorithm() {
+        let args = "app --algorithm=quicklife".split_whitespace();
+        let error = Arguments::try_parse_from(args).expect_err("invalid args");
+        assert!(matches!(error.kind(), ErrorKind::ValueValidation));
+    }
+
+    #[test]
+    fn valid_start_and_generations() {
+        let args = "app --start=10000 --generations=10100".split_whitespace();
+        let args = Arguments::try_parse_from(args).expect("valid args");
+        assert_eq!(args.start, Some(10000));
+        assert_eq!(args.generations, Some(10100));
+    }
+
+    #[test]
+    fn invalid_generations() {
+        let args = "app --generations=-1".split_whitespace();
+        let error = Arguments::try_parse_from(args).expect_err("invalid args");
+        assert!(matches!(error.kind(), ErrorKind::ValueValidation));
+    }
+
+    #[test]
+    fn valid_census() {
+        let args = "app --census=100 --seed=42 --report=./soups.txt".split_whitespace();
+        let args = Arguments::try_parse_from(args).expect("valid args");
+        assert_eq!(args.census, Some(100));
+        assert_eq!(args.seed, Some(42));
+        assert_eq!(args.report, Some(PathBuf::from("./soups.txt")));
+    }
+
+    #[test]
+    fn seed_requires_census() {
+        let args = "app --seed=42".split_whitespace();
+        let error = Arguments::try_parse_from(args).expect_err("invalid args");
+        assert!(matches!(error.kind(), ErrorKind::MissingRequiredArgument));
+    }
+
+    #[test]
+    fn census_conflicts_with_the_world_settings() {
+        for setting in [
+            "--world=./tests/data/initial_world.life",
+            "--pattern=glider",
+            "--viewport=0..5,0..5",
+            "--bounds=0..5,0..5",
+            "--rule=B36/S23",
+            "--topology=torus",
+            "--algorithm=hashlife",
+            "--start=10",
+        ] {
+            let args = ["app", "--census=10", setting];
+            let error = Arguments::try_parse_from(args).expect_err("invalid args");
+            assert!(
+                matches!(error.kind(), ErrorKind::ArgumentConflict),
+                "{}",
+                setting
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_rule() {
+        let args = "app --rule=B9/S23".split_whitespace();
+        let error = Arguments::try_parse_from(args).expect_err("invalid args");
+        assert!(matches!(error.kind(), ErrorKind::ValueValidation));
+    }
 }
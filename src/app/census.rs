@@ -0,0 +1,30 @@
+use super::arguments::Arguments;
+use crate::life::Census;
+
+impl From<&Arguments> for Census {
+    fn from(value: &Arguments) -> Self {
+        let seed = value.seed().copied().unwrap_or_else(rand::random);
+        let mut census = Census::new(seed);
+        if let Some(limit) = value.generations() {
+            census.with_limit(*limit);
+        }
+        census
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use clap::Parser;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn can_be_created_from_census_args() {
+        let args = "app --census=10 --seed=42 --generations=500".split_whitespace();
+        let args = Arguments::parse_from(args);
+        let census = Census::from(&args);
+        assert_eq!(census.seed(), 42);
+        assert_eq!(census.limit(), 500);
+    }
+}
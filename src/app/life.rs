@@ -1,29 +1,75 @@
+use std::fmt::Display;
+
 use thiserror::Error;
 
 use super::arguments::Arguments;
-use crate::life::{Generations, World, WorldError};
+use crate::life::{Engine, Generations, World, WorldError};
 
 #[derive(Debug, Error)]
 pub enum LifeError {
-    #[error("cannot create world")]
+    #[error("cannot create world: {0}")]
     CannotCreateWorld(#[from] WorldError),
 }
 
 #[derive(Debug)]
-#[repr(transparent)]
-pub struct Life(Generations);
+pub struct Life<E: Engine = World> {
+    generations: Generations<E>,
+    start: u64,
+    limit: Option<u64>,
+}
 
-impl Life {
+impl<E: Engine> Life<E> {
+    /// Starts displaying at the `start` generation, jumping straight there.
+    pub fn with_start(&mut self, start: u64) {
+        self.start = start;
+    }
+
+    /// Stops after the `limit` generation, even if no generation has repeated.
+    pub fn with_limit(&mut self, limit: u64) {
+        self.limit = Some(limit);
+    }
+
+    /// The cycle found by the run, if any, such as "generation 4: period 4 from generation 0,
+    /// moving (-1, 1) at c/4 diagonal".
+    fn summary(&self) -> String {
+        let generation = self.generations.generation();
+        match self.generations.cycle() {
+            Some(cycle) => format!("generation {}: {}", generation, cycle),
+            None => format!("generation {}: no cycle found", generation),
+        }
+    }
+}
+
+impl<E: Engine + Display> Life<E> {
     pub fn run(&mut self) {
-        let generations = &mut self.0;
+        let generations = &mut self.generations;
+        let start = self.start.saturating_sub(generations.generation());
+        generations.advance(start);
         print!("{}{}", ansi::CLEAR_SCREEN, ansi::HOME);
         println!("{}", generations.current());
-        while let Some(generation) = generations.next_generation() {
+        let is_within_limit = |generation: u64| self.limit.is_none_or(|limit| generation < limit);
+        // Spaceships never repeat exactly on an unbounded plane, so the run ends once any cycle
+        // is found, rather than when a generation repeats.
+        while is_within_limit(generations.generation())
+            && generations.cycle().is_none()
+            && let Some(generation) = generations.next_generation()
+        {
             print!("{}", ansi::HOME);
             println!("{}", generation);
             let duration = std::time::Duration::from_millis(200);
             std::thread::sleep(duration);
         }
+        println!("{}", self.summary());
+    }
+}
+
+impl<E: Engine> From<Generations<E>> for Life<E> {
+    fn from(value: Generations<E>) -> Self {
+        Self {
+            generations: value,
+            start: 0,
+            limit: None,
+        }
     }
 }
 
@@ -44,11 +90,29 @@ impl TryFrom<&Arguments> for Life {
         }
 
         if let Some(bounds) = value.bounds() {
-            world.with_bounds(bounds);
+            world.with_bounds(bounds)?;
+        }
+
+        if let Some(rule) = value.rule() {
+            world.with_rule(rule);
         }
 
-        let generations = Generations::new(world);
-        Ok(Life(generations))
+        if let Some(topology) = value.topology() {
+            world.with_topology(topology)?;
+        }
+
+        if let Some(algorithm) = value.algorithm() {
+            world.with_algorithm(algorithm);
+        }
+
+        let mut life = Life::from(Generations::new(world));
+        if let Some(start) = value.start() {
+            life.with_start(*start);
+        }
+        if let Some(limit) = value.generations() {
+            life.with_limit(*limit);
+        }
+        Ok(life)
     }
 }
 
@@ -62,6 +126,7 @@ mod test {
     use clap::Parser;
 
     use super::*;
+    use crate::life::Topology;
 
     #[test]
     fn can_be_created_from_valid_pattern_arg() {
@@ -107,4 +172,77 @@ mod test {
         let mut app = Life::try_from(&args).expect("valid life");
         app.run();
     }
+
+    #[test]
+    fn will_run_to_completion_with_rule() {
+        let args = "app --pattern=blinker --rule=B36/S23".split_whitespace();
+        let args = Arguments::parse_from(args);
+        let mut app = Life::try_from(&args).expect("valid life");
+        app.run();
+    }
+
+    #[test]
+    fn cannot_be_created_with_a_sphere_on_oblong_bounds() {
+        let args = "app --pattern=glider --bounds=0..5,0..9 --topology=sphere".split_whitespace();
+        let args = Arguments::parse_from(args);
+        let error = Life::try_from(&args).expect_err("invalid life");
+        assert!(matches!(
+            error,
+            LifeError::CannotCreateWorld(WorldError::IncompatibleTopology(Topology::Sphere))
+        ));
+    }
+
+    #[test]
+    fn will_run_to_completion_with_hashlife() {
+        let args = "app --pattern=pulsar --algorithm=hashlife".split_whitespace();
+        let args = Arguments::parse_from(args);
+        let mut app = Life::try_from(&args).expect("valid life");
+        app.run();
+    }
+
+    #[test]
+    fn will_run_to_completion_on_a_torus() {
+        let args = "app --pattern=glider --bounds=0..5,0..5 --topology=torus".split_whitespace();
+        let args = Arguments::parse_from(args);
+        let mut app = Life::try_from(&args).expect("valid life");
+        app.run();
+    }
+
+    #[test]
+    fn will_run_a_glider_gun_from_start_to_limit() {
+        let args =
+            "app --pattern=gosper_glider_gun --start=1000 --generations=1002".split_whitespace();
+        let args = Arguments::parse_from(args);
+        let mut app = Life::try_from(&args).expect("valid life");
+        app.run();
+        assert_eq!(app.generations.generation(), 1002);
+    }
+
+    #[test]
+    fn will_summarise_the_cycle_found() {
+        let args = "app --pattern=glider".split_whitespace();
+        let args = Arguments::parse_from(args);
+        let mut app = Life::try_from(&args).expect("valid life");
+        app.run();
+        assert_eq!(
+            app.summary(),
+            "generation 4: period 4 from generation 0, moving (-1, 1) at c/4 diagonal"
+        );
+    }
+
+    #[test]
+    fn will_summarise_no_cycle_found() {
+        let args = "app --pattern=gosper_glider_gun --generations=1".split_whitespace();
+        let args = Arguments::parse_from(args);
+        let mut app = Life::try_from(&args).expect("valid life");
+        app.run();
+        assert_eq!(app.summary(), "generation 1: no cycle found");
+    }
+
+    #[test]
+    fn will_run_generations_to_completion() {
+        let world = World::try_from(&crate::life::Pattern::Toad).expect("valid pattern");
+        let mut app = Life::from(Generations::new(world));
+        app.run();
+    }
 }
@@ -1,4 +1,5 @@
 mod arguments;
+mod census;
 mod life;
 
 pub use arguments::Arguments;
@@ -4,6 +4,13 @@ mod life;
 pub mod prelude {
     pub use super::{
         app::{Arguments, Life},
-        life::{Bounds, Cell, Cells, CellsError, Generations, Pattern, Position, World},
+        life::{
+            Algorithm, AlgorithmError, Apgcode, ApgcodeError, Bounds, Cell, CellStates, Cells,
+            CellsError, Census, CensusError, Classification, Connectivity, Cycle, Edges, Engine,
+            FormatError, Generations, HashLife, HashLifeError, History, LargerThanLife, Life105,
+            Life106, Macrocell, Metadata, Neighbourhood, NeighbourhoodShape, Orientation, Pattern,
+            Plaintext, Position, Rle, Rule, RuleError, Search, Tiles, TilesError, Topology,
+            TopologyError, World, WorldError,
+        },
     };
 }
@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+use thiserror::*;
+
+#[derive(Debug, Error)]
+pub enum AlgorithmError {
+    #[error("invalid algorithm '{0}'")]
+    InvalidAlgorithm(String),
+}
+
+/// The algorithm a [World](super::world::World) uses to compute its generations. Worlds fall
+/// back to sets of cells whenever the chosen algorithm cannot support their rule, bounds or
+/// topology.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    /// [Tiles](super::tiles::Tiles) for large, dense populations, and sets of cells otherwise.
+    #[default]
+    Auto,
+    /// Sets of live cells, supporting every rule and topology.
+    Sets,
+    /// [HashLife](super::hashlife::HashLife), for long runs of unbounded two-state worlds.
+    HashLife,
+    /// Bit-packed [Tiles](super::tiles::Tiles), for dense soups of two-state totalistic rules.
+    Tiles,
+}
+
+impl FromStr for Algorithm {
+    type Err = AlgorithmError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "auto" => Ok(Algorithm::Auto),
+            "sets" => Ok(Algorithm::Sets),
+            "hashlife" => Ok(Algorithm::HashLife),
+            "tiles" => Ok(Algorithm::Tiles),
+            _ => Err(AlgorithmError::InvalidAlgorithm(s.into())),
+        }
+    }
+}
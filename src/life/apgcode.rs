@@ -0,0 +1,198 @@
+use std::str::FromStr;
+
+use thiserror::*;
+
+use super::{
+    cell::Cell, cells::Cells, classification::Classification, orientation::Orientation,
+    world::World,
+};
+
+/// The digits of the extended Wechsler format: a column of five cells, or a run of zeros.
+const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// The height of the strips an object is encoded in, one digit per column.
+const STRIP: isize = 5;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ApgcodeError {
+    #[error("apgcode '{0}' does not start with xs, xp or xq, a number and an underscore")]
+    InvalidPrefix(String),
+
+    #[error("invalid character '{0}' in apgcode")]
+    InvalidCharacter(char),
+
+    #[error("apgcode ends in the middle of a run of zeros")]
+    IncompleteRun,
+
+    #[error("still life of {0} cells has {1}")]
+    PopulationMismatch(usize, usize),
+}
+
+/// An object's identifier in the extended Wechsler format used by
+/// [apgsearch](https://conwaylife.com/wiki/Apgsearch), such as `xs4_33` for the block, `xp2_7`
+/// for the blinker or `xq4_153` for the glider. The code is the same whichever phase and
+/// orientation of the object it is made from.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Apgcode(String);
+
+impl Apgcode {
+    /// The code of `object`, as classified; `None` unless the object is a still life, an
+    /// oscillator or a spaceship in Conway's Life.
+    pub fn new(object: &Cells, classification: &Classification) -> Option<Self> {
+        let (prefix, period) = match classification {
+            Classification::StillLife => (format!("xs{}", object.len()), 1),
+            Classification::Oscillator { period } => (format!("xp{}", period), *period),
+            Classification::Spaceship { period, .. } => (format!("xq{}", period), *period),
+            _ => return None,
+        };
+
+        let mut world = World::from(object.clone());
+        let wechsler = (0..period)
+            .flat_map(|_| {
+                let phase = world.live_cells().clone();
+                world.next_generation();
+                Orientation::ALL
+                    .map(|orientation| wechsler(&phase.clone().transform(&orientation).normalise()))
+            })
+            .min_by(|lhs, rhs| lhs.len().cmp(&rhs.len()).then(lhs.cmp(rhs)))?;
+
+        Some(Self(format!("{}_{}", prefix, wechsler)))
+    }
+
+    /// The number of generations the object takes to repeat, one for still lifes.
+    pub fn period(&self) -> u64 {
+        match parts(&self.0).expect("valid apgcode") {
+            ('s', _, _) => 1,
+            (_, period, _) => period,
+        }
+    }
+
+    /// Whether the object is a spaceship.
+    pub fn is_moving(&self) -> bool {
+        self.0.starts_with("xq")
+    }
+
+    /// The object, in the phase and orientation encoded, with its bounding box at the origin.
+    pub fn cells(&self) -> Cells {
+        let (_, _, wechsler) = parts(&self.0).expect("valid apgcode");
+        cells(wechsler).expect("valid apgcode")
+    }
+}
+
+/// The kind of object, its population or period, and the Wechsler code of an apgcode.
+fn parts(code: &str) -> Result<(char, u64, &str), ApgcodeError> {
+    let invalid_prefix = || ApgcodeError::InvalidPrefix(code.into());
+    let (prefix, wechsler) = code.split_once('_').ok_or_else(invalid_prefix)?;
+    let kind = prefix
+        .strip_prefix('x')
+        .and_then(|prefix| prefix.chars().next())
+        .filter(|kind| matches!(kind, 's' | 'p' | 'q'))
+        .ok_or_else(invalid_prefix)?;
+    let number = prefix[2..]
+        .parse::<u64>()
+        .ok()
+        .filter(|number| *number > 0)
+        .ok_or_else(invalid_prefix)?;
+    Ok((kind, number, wechsler))
+}
+
+/// The cells of a Wechsler code, reversing [wechsler].
+fn cells(wechsler: &str) -> Result<Cells, ApgcodeError> {
+    let digit = |c: char| DIGITS.iter().position(|d| *d as char == c);
+    let mut cells = Vec::new();
+    for (strip, code) in wechsler.split('z').enumerate() {
+        let mut column = 0;
+        let mut chars = code.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                'w' => column += 2,
+                'x' => column += 3,
+                'y' => {
+                    let run = chars.next().ok_or(ApgcodeError::IncompleteRun)?;
+                    column += 4 + digit(run).ok_or(ApgcodeError::InvalidCharacter(run))?;
+                }
+                c => {
+                    let value = digit(c)
+                        .filter(|value| *value < 1 << STRIP)
+                        .ok_or(ApgcodeError::InvalidCharacter(c))?;
+                    let rows = (0..STRIP).filter(|bit| value & 1 << bit != 0);
+                    let row = |bit| strip as isize * STRIP + bit;
+                    cells.extend(rows.map(|bit| Cell::new(row(bit), column as isize)));
+                    column += 1;
+                }
+            }
+        }
+    }
+    Ok(Cells::from_iter(cells))
+}
+
+impl FromStr for Apgcode {
+    type Err = ApgcodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, number, wechsler) = parts(s)?;
+        let cells = cells(wechsler)?;
+        if kind == 's' && cells.len() as u64 != number {
+            Err(ApgcodeError::PopulationMismatch(
+                number as usize,
+                cells.len(),
+            ))?;
+        }
+        Ok(Self(s.into()))
+    }
+}
+
+/// The Wechsler code of `cells`, whose bounding box starts at the origin: each strip of five
+/// rows is a digit per column, bit `n` being the cell in row `n` of the strip, with runs of zeros
+/// shortened and those ending a strip dropped; `z` separates the strips.
+fn wechsler(cells: &Cells) -> String {
+    let bounds = cells.bounds();
+    if !bounds.is_defined() {
+        return String::new();
+    }
+
+    let strips = (bounds.height() as isize + STRIP - 1) / STRIP;
+    let columns = bounds.width() as isize;
+    let strips = (0..strips).map(|strip| {
+        let mut code = String::new();
+        let mut zeros = 0;
+        for column in 0..columns {
+            let digit = (0..STRIP)
+                .filter(|bit| cells.contains(&Cell::new(strip * STRIP + bit, column)))
+                .fold(0, |digit, bit| digit | 1 << bit);
+            if digit == 0 {
+                zeros += 1;
+            } else {
+                push_zeros(&mut code, zeros);
+                zeros = 0;
+                code.push(DIGITS[digit] as char);
+            }
+        }
+        code
+    });
+    strips.collect::<Vec<_>>().join("z")
+}
+
+/// Appends a run of `zeros` as `0`, `w` for two, `x` for three, or `y` and a digit for four to
+/// thirty nine.
+fn push_zeros(code: &mut String, mut zeros: usize) {
+    while zeros > 0 {
+        let run = zeros.min(4 + DIGITS.len() - 1);
+        match run {
+            1 => code.push('0'),
+            2 => code.push('w'),
+            3 => code.push('x'),
+            run => {
+                code.push('y');
+                code.push(DIGITS[run - 4] as char);
+            }
+        }
+        zeros -= run;
+    }
+}
+
+impl std::fmt::Display for Apgcode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -21,6 +21,11 @@ impl Bounds {
     }
 
     pub fn encompass(&mut self, cell: &Cell) {
+        if !self.is_defined() {
+            *self = Self::new(cell.row()..=cell.row(), cell.column()..=cell.column());
+            return;
+        }
+
         let Self { rows, columns } = self;
         let (r_min, r_max) = (*rows.start(), *rows.end());
         let (c_min, c_max) = (*columns.start(), *columns.end());
@@ -36,6 +41,18 @@ impl Bounds {
     pub fn columns(&self) -> &RangeInclusive<isize> {
         &self.columns
     }
+
+    pub fn contains(&self, cell: &Cell) -> bool {
+        self.rows.contains(&cell.row()) && self.columns.contains(&cell.column())
+    }
+
+    pub fn width(&self) -> usize {
+        self.columns.clone().count()
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.clone().count()
+    }
 }
 
 impl Default for Bounds {
@@ -65,4 +82,11 @@ mod test {
     fn default_bounds_columns_are_undefined() {
         assert!(Bounds::default().columns().is_empty());
     }
+
+    #[test]
+    fn encompassing_from_default_bounds_is_exact() {
+        let mut bounds = Bounds::default();
+        bounds.encompass(&Cell::new(-3, 5));
+        assert_eq!(bounds, Bounds::new(-3..=-3, 5..=5));
+    }
 }
@@ -1,7 +1,6 @@
 use std::{
     collections::HashSet,
-    iter::Rev,
-    ops::{Deref, DerefMut, RangeInclusive},
+    ops::{Deref, DerefMut},
     path::Path,
 };
 
@@ -9,7 +8,14 @@ use hashable::HashableHashSet;
 use rayon::prelude::*;
 use thiserror::*;
 
-use super::{bounds::Bounds, cell::Cell};
+use super::{
+    bounds::Bounds,
+    cell::Cell,
+    connectivity::{Connectivity, placed},
+    orientation::Orientation,
+    position::Position,
+    search::Search,
+};
 
 #[derive(Debug, Error)]
 pub enum CellsError {
@@ -37,45 +43,95 @@ impl Cells {
         bounds
     }
 
+    /// Rotates the cells `n` quarter turns clockwise within their bounds.
     pub fn rotate(self, n: isize) -> Self {
         const ORDER: isize = 4;
-        let cycle = n.rem_euclid(ORDER);
-        let bounds = self.bounds();
-        let row = bounds.rows();
-        let row_reversed = row.clone().rev();
-        let column = bounds.columns();
-        let column_reversed = column.clone().rev();
-
-        let rev_index = |range: &RangeInclusive<isize>, rev: &Rev<RangeInclusive<isize>>| {
-            let enumerated_range = Vec::from_iter(range.clone().enumerate());
-            let rev = Vec::from_iter(rev.clone());
-            move |original: isize| {
-                let index = enumerated_range
-                    .iter()
-                    .find_map(|(i, c)| (*c == original).then_some(i))
-                    .unwrap();
-                rev[*index]
-            }
+        let orientation = match n.rem_euclid(ORDER) {
+            0 => Orientation::Identity,
+            1 => Orientation::Rotate90,
+            2 => Orientation::Rotate180,
+            _ => Orientation::Rotate270, // 3, unreachable -1 & 4 conditions.
         };
+        self.transform(&orientation)
+    }
 
-        let row_reversed = rev_index(row, &row_reversed);
-        let column_reversed = rev_index(column, &column_reversed);
+    /// Reflects the cells left to right within their bounds.
+    pub fn flip_horizontal(self) -> Self {
+        self.transform(&Orientation::FlipHorizontal)
+    }
+
+    /// Reflects the cells top to bottom within their bounds.
+    pub fn flip_vertical(self) -> Self {
+        self.transform(&Orientation::FlipVertical)
+    }
 
+    /// Reflects the cells in the diagonal from their top left, exchanging rows and columns.
+    pub fn transpose(self) -> Self {
+        self.transform(&Orientation::Transpose)
+    }
+
+    /// Reflects the cells in the diagonal from their top right.
+    pub fn anti_transpose(self) -> Self {
+        self.transform(&Orientation::AntiTranspose)
+    }
+
+    /// Reorients the cells within their bounds.
+    pub fn transform(self, orientation: &Orientation) -> Self {
+        if *orientation == Orientation::Identity {
+            return self;
+        }
+
+        let bounds = self.bounds();
         let new_cells = self
             .0
             .par_iter()
-            .map(|c| {
-                match cycle {
-                    0 => *c,
-                    1 => Cell::new(c.column(), row_reversed(c.row())),
-                    2 => Cell::new(row_reversed(c.row()), column_reversed(c.column())),
-                    _ => Cell::new(column_reversed(c.column()), c.row()), // 3, unreachable -1 & 4 conditions.
-                }
-            })
+            .map(|c| orientation.apply(c, &bounds))
             .collect::<Vec<_>>();
 
         Cells::from_iter(new_cells)
     }
+
+    /// Moves every cell by `offset`.
+    pub fn translate(self, offset: &Position) -> Self {
+        Cells::from_iter(self.0.iter().map(|c| *c + *offset))
+    }
+
+    /// Moves the cells so that their bounds start at the origin.
+    pub fn normalise(self) -> Self {
+        let bounds = self.bounds();
+        if !bounds.is_defined() {
+            return self;
+        }
+
+        let offset = Position::new(-bounds.rows().start(), -bounds.columns().start());
+        self.translate(&offset)
+    }
+
+    /// The cells split into the objects they make up, each moved to the origin, with the
+    /// position it was moved from.
+    pub fn objects(&self, connectivity: &Connectivity) -> Vec<(Cells, Position)> {
+        placed(connectivity.components(self))
+    }
+
+    /// Where the searched for pattern appears, as the top left of its bounds, and in which
+    /// orientation.
+    pub fn find(&self, search: &Search) -> Vec<(Position, Orientation)> {
+        search.occurrences(self)
+    }
+
+    /// The cells in the orientation that comes first when the cells of each, moved to the origin,
+    /// are listed in order; so equal for every orientation and position of the same cells.
+    pub fn canonical(&self) -> Self {
+        Orientation::ALL
+            .iter()
+            .map(|orientation| self.clone().transform(orientation).normalise())
+            .min_by_key(|cells| {
+                let mut ordered = Vec::from_iter(cells.iter().copied());
+                ordered.sort();
+                ordered
+            })
+            .expect("eight orientations")
+    }
 }
 
 impl Deref for Cells {
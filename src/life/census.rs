@@ -0,0 +1,195 @@
+use std::{collections::HashMap, path::Path};
+
+use rayon::prelude::*;
+use thiserror::*;
+
+use super::{
+    apgcode::Apgcode, classification::Classification, connectivity::Connectivity, engine::Engine,
+    rule::Rule, tiles::Tiles, world::World,
+};
+
+/// The longest period of population that a settled soup is recognised by.
+const MAX_PERIOD: usize = 60;
+
+/// The number of generations the population must have repeated for before a soup is settled.
+const SETTLED_FOR: usize = 2 * MAX_PERIOD;
+
+/// The most generations an object of the ash is run for to classify it.
+const OBJECT_LIMIT: u64 = 1000;
+
+#[derive(Debug, Error)]
+pub enum CensusError {
+    #[error("io error: {0}")]
+    FileError(#[from] std::io::Error),
+}
+
+/// A census of the objects left by random soups, in the spirit of
+/// [apgsearch](https://conwaylife.com/wiki/Apgsearch). Each soup is run until its population
+/// repeats, and the ash it settles into is split into objects, each tallied by its [Apgcode], or
+/// counted as unresolved when it is neither a still life, an oscillator nor a spaceship. Soups
+/// that have not settled within the limit are counted, but not tallied.
+#[derive(Clone, Debug)]
+pub struct Census {
+    seed: u64,
+    limit: u64,
+    soups: u64,
+    unsettled: u64,
+    unresolved: u64,
+    tally: HashMap<Apgcode, u64>,
+}
+
+impl Census {
+    /// A census of the soups seeded by `seed` and the seeds after it.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            limit: 10_000,
+            soups: 0,
+            unsettled: 0,
+            unresolved: 0,
+            tally: HashMap::new(),
+        }
+    }
+
+    /// Gives up on soups that have not settled by the `limit` generation.
+    pub fn with_limit(&mut self, limit: u64) {
+        self.limit = limit;
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// The number of soups run.
+    pub fn soups(&self) -> u64 {
+        self.soups
+    }
+
+    /// The number of soups that did not settle within the limit.
+    pub fn unsettled(&self) -> u64 {
+        self.unsettled
+    }
+
+    /// The number of objects found that have no apgcode.
+    pub fn unresolved(&self) -> u64 {
+        self.unresolved
+    }
+
+    /// The number of each object found, by apgcode.
+    pub fn tally(&self) -> &HashMap<Apgcode, u64> {
+        &self.tally
+    }
+
+    /// Runs the next `soups` soups, in parallel, adding their objects to the tally.
+    pub fn run(&mut self, soups: u64) {
+        let first = self.seed.wrapping_add(self.soups);
+        let ashes = (0..soups)
+            .into_par_iter()
+            .map(|soup| ash(first.wrapping_add(soup), self.limit))
+            .collect::<Vec<_>>();
+
+        for ash in ashes {
+            match ash {
+                Some(objects) => objects.into_iter().for_each(|apgcode| match apgcode {
+                    Some(apgcode) => *self.tally.entry(apgcode).or_default() += 1,
+                    None => self.unresolved += 1,
+                }),
+                None => self.unsettled += 1,
+            }
+        }
+        self.soups += soups;
+    }
+
+    /// Writes the report to `path`.
+    pub fn save(&self, path: &Path) -> Result<(), CensusError> {
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+}
+
+/// The apgcodes of the objects that the soup seeded by `seed` settles into, `None` for those
+/// without one, or `None` if it has not settled by the `limit` generation.
+fn ash(seed: u64, limit: u64) -> Option<Vec<Option<Apgcode>>> {
+    let mut soup = Tiles::new(&Rule::default()).expect("tiles support Life");
+    soup.with_cells(World::random_with_seed(seed).live_cells());
+    let mut populations = vec![Engine::population(&soup)];
+    while !is_settled(&populations) {
+        if populations.len() as u64 > limit {
+            return None;
+        }
+        Engine::step(&mut soup);
+        populations.push(Engine::population(&soup));
+    }
+
+    let ash = World::from(soup.cells());
+    let objects = ash
+        .objects(&Connectivity::WithinTwo)
+        .into_iter()
+        .map(|(object, _)| {
+            let classification = Classification::of(World::from(object.clone()), OBJECT_LIMIT);
+            Apgcode::new(&object, &classification)
+        });
+    Some(objects.collect())
+}
+
+/// Whether the population has repeated with a period of up to [MAX_PERIOD] for the last
+/// [SETTLED_FOR] generations, as it does once the ash has settled and any spaceships have left.
+fn is_settled(populations: &[usize]) -> bool {
+    if populations.len() < SETTLED_FOR + MAX_PERIOD {
+        return false;
+    }
+    let recent = populations.len() - SETTLED_FOR..populations.len();
+    (1..=MAX_PERIOD).any(|period| {
+        recent
+            .clone()
+            .all(|g| populations[g] == populations[g - period])
+    })
+}
+
+impl std::fmt::Display for Census {
+    /// The report: a header of comments, then the objects from the most common, such as
+    /// `xs4_33 1024`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "# Census of {} soups from seed {}",
+            self.soups, self.seed
+        )?;
+        writeln!(
+            f,
+            "# {} soups did not settle within {} generations",
+            self.unsettled, self.limit
+        )?;
+        writeln!(
+            f,
+            "# {} objects were neither still lifes, oscillators nor spaceships",
+            self.unresolved
+        )?;
+        let mut tally = Vec::from_iter(&self.tally);
+        tally.sort_by(|(lhs, lhs_count), (rhs, rhs_count)| {
+            rhs_count.cmp(lhs_count).then(lhs.cmp(rhs))
+        });
+        for (code, count) in tally {
+            writeln!(f, "{} {}", code, count)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn settled_populations_repeat() {
+        let oscillating = Vec::from_iter((0..300).map(|g| 10 + g % 3));
+        assert!(is_settled(&oscillating));
+        let growing = Vec::from_iter(0..300);
+        assert!(!is_settled(&growing));
+        assert!(!is_settled(&oscillating[..100]));
+    }
+}
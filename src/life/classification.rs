@@ -0,0 +1,117 @@
+use super::{cycle::Cycle, engine::Engine, generations::Generations, position::Position};
+
+/// What a pattern turns out to be when it is run: an object that settles into a
+/// [Cycle](super::cycle::Cycle), one that grows steadily, as guns and puffers do, or one that
+/// dies out. Patterns that do none of these within the generations allowed are unresolved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Classification {
+    /// Every cell is dead by `generation`.
+    Dies {
+        generation: u64,
+    },
+
+    StillLife,
+
+    Oscillator {
+        period: u64,
+    },
+
+    /// The cells repeat every `period` generations, moved along by the `displacement`.
+    Spaceship {
+        period: u64,
+        displacement: Position,
+    },
+
+    /// The population grows by `growth` cells every `period` generations.
+    Growing {
+        period: u64,
+        growth: usize,
+    },
+
+    Unresolved,
+}
+
+impl Classification {
+    /// Runs `engine` for up to `limit` generations, until it dies out or enters a cycle, and
+    /// otherwise looks for steady growth over the latter half of the run.
+    pub fn of<E: Engine>(engine: E, limit: u64) -> Self {
+        let mut generations = Generations::new(engine);
+        let mut populations = vec![generations.current().population()];
+        while populations.last() != Some(&0)
+            && generations.cycle().is_none()
+            && generations.generation() < limit
+        {
+            generations.next_generation();
+            populations.push(generations.current().population());
+        }
+
+        if populations.last() == Some(&0) {
+            return Classification::Dies {
+                generation: generations.generation(),
+            };
+        }
+
+        match generations.cycle() {
+            Some(cycle) if cycle.is_moving() => Classification::Spaceship {
+                period: cycle.period(),
+                displacement: *cycle.displacement(),
+            },
+            Some(cycle) if cycle.period() == 1 => Classification::StillLife,
+            Some(cycle) => Classification::Oscillator {
+                period: cycle.period(),
+            },
+            None => steady_growth(&populations)
+                .map(|(period, growth)| Classification::Growing { period, growth })
+                .unwrap_or(Classification::Unresolved),
+        }
+    }
+}
+
+/// The shortest period over which the population grew by the same number of cells, every
+/// generation of the latter half of the run, and that number.
+fn steady_growth(populations: &[usize]) -> Option<(u64, usize)> {
+    let last = populations.len() - 1;
+    (1..=last / 4).find_map(|period| {
+        let growth = populations[last].checked_sub(populations[last - period])?;
+        let is_steady =
+            (last / 2..=last - period).all(|g| populations[g + period] == populations[g] + growth);
+        (growth > 0 && is_steady).then_some((period as u64, growth))
+    })
+}
+
+impl std::fmt::Display for Classification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Classification::Dies { generation } => write!(f, "dies at generation {}", generation),
+            Classification::StillLife => write!(f, "still life"),
+            Classification::Oscillator { period } => write!(f, "oscillator of period {}", period),
+            Classification::Spaceship {
+                period,
+                displacement,
+            } => {
+                let speed = Cycle::new(0, *period, *displacement).speed();
+                let speed = speed.expect("spaceships move");
+                write!(f, "spaceship of period {} moving at {}", period, speed)
+            }
+            Classification::Growing { period, growth } => write!(
+                f,
+                "growing by {} cells every {} generations",
+                growth, period
+            ),
+            Classification::Unresolved => write!(f, "unresolved"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn growth_must_be_steady() {
+        assert_eq!(steady_growth(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), Some((1, 1)));
+        assert_eq!(steady_growth(&[1, 3, 2, 4, 3, 5, 4, 6, 5]), Some((2, 1)));
+        assert_eq!(steady_growth(&[1, 2, 3, 4, 5, 6, 7, 8, 8]), None);
+        assert_eq!(steady_growth(&[5, 5, 5, 5, 5, 5, 5, 5, 5]), None);
+    }
+}
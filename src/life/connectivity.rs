@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use super::{cells::Cells, engine::top_left, position::Position};
+
+/// Which cells belong to the same object: those that are near enough to each other, directly
+/// or through a chain of other cells of the object.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Cells that are neighbours, within one row and column of each other.
+    Neighbours,
+    /// Cells within two rows and columns of each other, which are all the cells that can affect
+    /// each other in the next generation; the usual clustering for the objects of Life.
+    #[default]
+    WithinTwo,
+}
+
+impl Connectivity {
+    /// The greatest number of rows or columns between connected cells.
+    pub fn reach(&self) -> isize {
+        match self {
+            Connectivity::Neighbours => 1,
+            Connectivity::WithinTwo => 2,
+        }
+    }
+
+    /// The cells split into their connected components, in place.
+    pub(crate) fn components(&self, cells: &Cells) -> Vec<Cells> {
+        let reach = self.reach();
+        let offsets = (-reach..=reach)
+            .flat_map(|dr| (-reach..=reach).map(move |dc| Position::new(dr, dc)))
+            .filter(|offset| *offset != Position::new(0, 0))
+            .collect::<Vec<_>>();
+
+        let mut unseen = cells.iter().copied().collect::<HashSet<_>>();
+        let mut components = Vec::new();
+        while let Some(&first) = unseen.iter().next() {
+            unseen.remove(&first);
+            let mut component = vec![first];
+            let mut next = 0;
+            while let Some(&cell) = component.get(next) {
+                next += 1;
+                for offset in offsets.iter() {
+                    let near = cell + *offset;
+                    if unseen.remove(&near) {
+                        component.push(near);
+                    }
+                }
+            }
+            components.push(Cells::from_iter(component));
+        }
+        components
+    }
+}
+
+/// The objects moved to the origin, each with the position of its top left, in order of those
+/// positions.
+pub(crate) fn placed(objects: impl IntoIterator<Item = Cells>) -> Vec<(Cells, Position)> {
+    let mut placed = objects
+        .into_iter()
+        .map(|object| {
+            let origin = top_left(&object);
+            (object.normalise(), origin)
+        })
+        .collect::<Vec<_>>();
+    placed.sort_by_key(|(object, origin)| (origin.row(), origin.column(), object.len()));
+    placed
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::life::cell::Cell;
+
+    #[test]
+    fn components_are_separated_by_more_than_the_reach() {
+        let cells =
+            Cells::from_iter([(0, 0), (0, 1), (2, 3), (6, 0)].map(|(r, c)| Cell::new(r, c)));
+        let sizes = |connectivity: Connectivity| {
+            let mut sizes = Vec::from_iter(connectivity.components(&cells).iter().map(|c| c.len()));
+            sizes.sort();
+            sizes
+        };
+        assert_eq!(sizes(Connectivity::WithinTwo), vec![1, 3]);
+        assert_eq!(sizes(Connectivity::Neighbours), vec![1, 1, 2]);
+    }
+}
@@ -0,0 +1,82 @@
+use super::position::Position;
+
+/// The cycle that a run of generations has entered: from the `entry` generation, the cells
+/// repeat every `period` generations, moved along by the `displacement`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    entry: u64,
+    period: u64,
+    displacement: Position,
+}
+
+impl Cycle {
+    pub fn new(entry: u64, period: u64, displacement: Position) -> Self {
+        Self {
+            entry,
+            period,
+            displacement,
+        }
+    }
+
+    /// The first generation of the cycle.
+    pub fn entry(&self) -> u64 {
+        self.entry
+    }
+
+    pub fn period(&self) -> u64 {
+        self.period
+    }
+
+    /// How far the cells move each period.
+    pub fn displacement(&self) -> &Position {
+        &self.displacement
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.displacement != Position::new(0, 0)
+    }
+
+    /// The [speed](https://conwaylife.com/wiki/Speed) of the cells, such as `c/4 diagonal`,
+    /// `2c/5 orthogonal` or `(2,1)c/6` for an oblique knightship; `None` when they stay put.
+    pub fn speed(&self) -> Option<String> {
+        if !self.is_moving() {
+            return None;
+        }
+
+        let rows = self.displacement.row().unsigned_abs() as u64;
+        let columns = self.displacement.column().unsigned_abs() as u64;
+        let (major, minor) = (rows.max(columns), rows.min(columns));
+        let fraction = |distance: u64| {
+            let divisor = gcd(distance, self.period);
+            match (distance / divisor, self.period / divisor) {
+                (1, 1) => "c".into(),
+                (distance, 1) => format!("{}c", distance),
+                (1, period) => format!("c/{}", period),
+                (distance, period) => format!("{}c/{}", distance, period),
+            }
+        };
+
+        Some(if minor == 0 {
+            format!("{} orthogonal", fraction(major))
+        } else if minor == major {
+            format!("{} diagonal", fraction(major))
+        } else {
+            format!("({},{})c/{}", major, minor, self.period)
+        })
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl std::fmt::Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "period {} from generation {}", self.period, self.entry)?;
+        if let Some(speed) = self.speed() {
+            let (row, column) = (self.displacement.row(), self.displacement.column());
+            write!(f, ", moving ({}, {}) at {}", row, column, speed)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use std::{fmt::Debug, hash::Hash};
+
+use super::{bounds::Bounds, cell::Cell, cells::Cells, position::Position};
+
+/// A universe of cells that can be advanced a generation at a time, however it is represented.
+/// [Generations](super::generations::Generations), the terminal runner and the analysis tools
+/// work with any engine. Engines are cloned to keep checkpoints of their past.
+pub trait Engine: Clone {
+    /// Everything that determines the engine's future, so that equal snapshots repeat.
+    type Snapshot: Debug + Eq + Hash;
+
+    /// Advances the universe by one generation.
+    fn step(&mut self);
+
+    /// Advances the universe by `generations`, by whatever means is quickest.
+    fn step_n(&mut self, generations: u64) {
+        (0..generations).for_each(|_| self.step());
+    }
+
+    /// The number of live cells.
+    fn population(&self) -> usize;
+
+    /// The smallest bounds enclosing every live cell; undefined when there are none.
+    fn bounding_box(&self) -> Bounds;
+
+    /// The live cells within `rect`.
+    fn cells_in(&self, rect: &Bounds) -> Cells;
+
+    /// Brings `cell` to life.
+    fn set_cell(&mut self, cell: &Cell);
+
+    /// Kills `cell`.
+    fn clear_cell(&mut self, cell: &Cell);
+
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// The snapshot moved so that its bounding box starts at the origin, with the position it
+    /// was moved from, so that moving patterns can be recognised. `None` when the universe is
+    /// bounded, as patterns cannot keep moving there.
+    fn normalised_snapshot(&self) -> Option<(Self::Snapshot, Position)>;
+}
+
+/// The top left of the bounding box of `cells`, or the origin when there are none.
+pub(crate) fn top_left(cells: &Cells) -> Position {
+    let bounds = cells.bounds();
+    if bounds.is_defined() {
+        Position::new(*bounds.rows().start(), *bounds.columns().start())
+    } else {
+        Position::new(0, 0)
+    }
+}
+
+/// The cells moved from their top left to the origin, and their top left.
+pub(crate) fn normalised(cells: &Cells) -> (Cells, Position) {
+    (cells.clone().normalise(), top_left(cells))
+}
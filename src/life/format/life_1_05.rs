@@ -0,0 +1,162 @@
+use std::{collections::BTreeMap, path::Path};
+
+use super::{FormatError, Metadata};
+use crate::life::{cell::Cell, cells::Cells, position::Position, rule::Rule};
+
+const MAX_LINE_LENGTH: isize = 80;
+
+/// A pattern in [Life 1.05](https://conwaylife.com/wiki/Life_1.05) format: blocks of `.` and `*`
+/// rows, each positioned absolutely by a preceding `#P x y` line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Life105 {
+    cells: Cells,
+    rule: Option<Rule>,
+    metadata: Metadata,
+}
+
+impl Life105 {
+    pub const HEADER: &str = "#Life 1.05";
+
+    pub fn cells(&self) -> &Cells {
+        &self.cells
+    }
+
+    /// The `#R` rule; `None` for the `#N` normal (Conway) rule.
+    pub fn rule(&self) -> Option<&Rule> {
+        self.rule.as_ref()
+    }
+
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), FormatError> {
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+}
+
+fn parse_position(s: &str) -> Option<Position> {
+    let mut coordinates = s.split_whitespace().map(|n| n.parse::<isize>());
+    let (column, row) = (coordinates.next()?.ok()?, coordinates.next()?.ok()?);
+    coordinates
+        .next()
+        .is_none()
+        .then(|| Position::new(row, column))
+}
+
+impl TryFrom<&str> for Life105 {
+    type Error = FormatError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut life = Life105::default();
+        let mut block = Position::new(0, 0);
+        let mut row = 0;
+
+        for (li, line) in value.lines().map(str::trim).enumerate() {
+            if line.starts_with(Self::HEADER) {
+                continue;
+            } else if let Some(description) = line.strip_prefix("#D") {
+                life.metadata.comments.push(description.trim().into());
+            } else if line.starts_with("#N") {
+                life.rule = None;
+            } else if let Some(rule) = line.strip_prefix("#R") {
+                life.rule = Some(rule.trim().parse()?);
+            } else if let Some(position) = line.strip_prefix("#P") {
+                block = parse_position(position).ok_or(FormatError::InvalidLine(li + 1))?;
+                row = 0;
+            } else if line.starts_with('#') {
+                continue;
+            } else {
+                for (ci, c) in line.chars().enumerate() {
+                    match c {
+                        '.' => {}
+                        '*' => {
+                            life.cells.insert(Cell::new(row, ci as isize) + block);
+                        }
+                        c => return Err(FormatError::UnexpectedCharacter(c, li + 1)),
+                    }
+                }
+                row += 1;
+            }
+        }
+
+        Ok(life)
+    }
+}
+
+impl TryFrom<&Path> for Life105 {
+    type Error = FormatError;
+
+    fn try_from(value: &Path) -> Result<Self, Self::Error> {
+        let content = std::fs::read_to_string(value)?;
+        Life105::try_from(content.as_str())
+    }
+}
+
+impl From<Life105> for Cells {
+    fn from(value: Life105) -> Self {
+        value.cells
+    }
+}
+
+impl From<&Cells> for Life105 {
+    fn from(value: &Cells) -> Self {
+        Self {
+            cells: value.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl std::fmt::Display for Life105 {
+    /// Patterns wider than a line are written as several side by side blocks.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", Self::HEADER)?;
+        for comment in self.metadata.comments() {
+            writeln!(f, "#D {}", comment)?;
+        }
+        match self.rule.filter(|r| *r != Rule::default()) {
+            Some(rule) if !rule.is_totalistic() => writeln!(f, "#R {}", rule)?,
+            Some(rule) => {
+                let digits = |is_set: fn(&Rule, usize) -> bool| {
+                    (0..=8)
+                        .filter(|n| is_set(&rule, *n))
+                        .map(|n| n.to_string())
+                        .collect::<String>()
+                };
+                writeln!(f, "#R {}/{}", digits(Rule::survives), digits(Rule::is_born))?
+            }
+            None => writeln!(f, "#N")?,
+        }
+
+        let left = *self.cells.bounds().columns().start();
+        let mut blocks = BTreeMap::<isize, Vec<Cell>>::new();
+        self.cells.iter().for_each(|c| {
+            let band = (c.column() - left).div_euclid(MAX_LINE_LENGTH);
+            blocks.entry(band).or_default().push(*c);
+        });
+
+        for (band, cells) in blocks {
+            let block_left = left + band * MAX_LINE_LENGTH;
+            let block = Cells::from_iter(cells);
+            let rows = block.bounds().rows().clone();
+            writeln!(f, "#P {} {}", block_left, rows.start())?;
+            for r in rows {
+                let mut columns = block
+                    .iter()
+                    .filter(|c| c.row() == r)
+                    .map(|c| c.column() - block_left)
+                    .collect::<Vec<_>>();
+                columns.sort();
+                let width = columns.last().map_or(1, |c| c + 1);
+                let line = (0..width)
+                    .map(|c| if columns.contains(&c) { '*' } else { '.' })
+                    .collect::<String>();
+                writeln!(f, "{}", line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
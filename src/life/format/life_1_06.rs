@@ -0,0 +1,86 @@
+use std::path::Path;
+
+use super::FormatError;
+use crate::life::{cell::Cell, cells::Cells};
+
+/// A pattern in [Life 1.06](https://conwaylife.com/wiki/Life_1.06) format: a list of absolute
+/// `x y` coordinates, one live cell per line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Life106 {
+    cells: Cells,
+}
+
+impl Life106 {
+    pub const HEADER: &str = "#Life 1.06";
+
+    pub fn cells(&self) -> &Cells {
+        &self.cells
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), FormatError> {
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for Life106 {
+    type Error = FormatError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut cells = Cells::default();
+
+        for (li, line) in value.lines().map(str::trim).enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let coordinates = line
+                .split_whitespace()
+                .map(|n| n.parse::<isize>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| FormatError::InvalidLine(li + 1))?;
+            let [column, row] = coordinates[..] else {
+                return Err(FormatError::InvalidLine(li + 1));
+            };
+            cells.insert(Cell::new(row, column));
+        }
+
+        Ok(Self { cells })
+    }
+}
+
+impl TryFrom<&Path> for Life106 {
+    type Error = FormatError;
+
+    fn try_from(value: &Path) -> Result<Self, Self::Error> {
+        let content = std::fs::read_to_string(value)?;
+        Life106::try_from(content.as_str())
+    }
+}
+
+impl From<Life106> for Cells {
+    fn from(value: Life106) -> Self {
+        value.cells
+    }
+}
+
+impl From<&Cells> for Life106 {
+    fn from(value: &Cells) -> Self {
+        Self {
+            cells: value.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for Life106 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ordered = Vec::from_iter(self.cells.iter());
+        ordered.sort();
+
+        writeln!(f, "{}", Self::HEADER)?;
+        for cell in ordered {
+            writeln!(f, "{} {}", cell.column(), cell.row())?;
+        }
+        Ok(())
+    }
+}
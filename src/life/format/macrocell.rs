@@ -0,0 +1,348 @@
+use std::{collections::HashMap, path::Path};
+
+use super::{FormatError, Metadata};
+use crate::life::{cell::Cell, cells::Cells, position::Position, rule::Rule, world::World};
+
+const HEADER: &str = "[M2]";
+const LEAF_LEVEL: u32 = 3;
+
+/// The level of the largest root, whose cells' coordinates all fit.
+const MAX_LEVEL: u32 = isize::BITS - 2;
+
+/// A pattern in Golly's [Macrocell](https://conwaylife.com/wiki/Macrocell) quadtree format.
+///
+/// The root node is centred on the origin, so cell coordinates are preserved absolutely.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Macrocell {
+    cells: Cells,
+    rule: Option<Rule>,
+    metadata: Metadata,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Node {
+    Leaf(Vec<Position>),
+    Branch(u32, [usize; 4]),
+}
+
+impl Node {
+    fn level(&self) -> u32 {
+        match self {
+            Node::Leaf(_) => LEAF_LEVEL,
+            Node::Branch(level, _) => *level,
+        }
+    }
+}
+
+impl Macrocell {
+    pub fn cells(&self) -> &Cells {
+        &self.cells
+    }
+
+    pub fn rule(&self) -> Option<&Rule> {
+        self.rule.as_ref()
+    }
+
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), FormatError> {
+        if self.level().is_none() {
+            return Err(FormatError::Unrepresentable(
+                "cells so far from the origin".into(),
+            ));
+        }
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    /// The level of the smallest root centred on the origin that holds every cell, if any does.
+    fn level(&self) -> Option<u32> {
+        let extent = self
+            .cells
+            .iter()
+            .map(|c| c.row().unsigned_abs().max(c.column().unsigned_abs()))
+            .max()
+            .unwrap_or(0);
+        (LEAF_LEVEL + 1..=MAX_LEVEL).find(|level| extent < 1 << (level - 1))
+    }
+
+    fn parse_comment(&mut self, comment: &str) -> Result<(), FormatError> {
+        if let Some(rule) = comment.strip_prefix('R') {
+            self.rule = Some(rule.trim().parse()?);
+        } else if let Some(name) = comment.strip_prefix('N') {
+            self.metadata.name = Some(name.trim().into());
+        } else if let Some(author) = comment.strip_prefix('O') {
+            self.metadata.author = Some(author.trim().into());
+        } else if let Some(text) = comment.strip_prefix('C') {
+            self.metadata.comments.push(text.trim().into());
+        }
+        Ok(())
+    }
+}
+
+fn parse_leaf(line: &str, line_number: usize) -> Result<Node, FormatError> {
+    let mut positions = Vec::new();
+    let (mut row, mut column) = (0, 0);
+    for c in line.chars() {
+        match c {
+            '.' => column += 1,
+            '*' => {
+                positions.push(Position::new(row, column));
+                column += 1;
+            }
+            '$' => {
+                row += 1;
+                column = 0;
+            }
+            c => return Err(FormatError::UnexpectedCharacter(c, line_number)),
+        }
+    }
+
+    let size = 1 << LEAF_LEVEL;
+    let is_inside = |p: &Position| p.row() < size && p.column() < size;
+    positions
+        .iter()
+        .all(is_inside)
+        .then_some(Node::Leaf(positions))
+        .ok_or(FormatError::InvalidLine(line_number))
+}
+
+fn parse_branch(line: &str, nodes: &[Node], line_number: usize) -> Result<Node, FormatError> {
+    let invalid = || FormatError::InvalidLine(line_number);
+
+    let numbers = line
+        .split_whitespace()
+        .map(|n| n.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| invalid())?;
+    let [level, nw, ne, sw, se] = numbers[..] else {
+        return Err(invalid());
+    };
+
+    let level = u32::try_from(level)
+        .ok()
+        .filter(|l| (LEAF_LEVEL + 1..=MAX_LEVEL).contains(l))
+        .ok_or_else(invalid)?;
+    let children = [nw, ne, sw, se];
+    let is_child = |&i: &usize| i == 0 || nodes.get(i - 1).is_some_and(|n| n.level() == level - 1);
+    children
+        .iter()
+        .all(is_child)
+        .then_some(Node::Branch(level, children))
+        .ok_or_else(invalid)
+}
+
+fn expand(nodes: &[Node], index: usize, origin: Position, cells: &mut Cells) {
+    let Some(node) = index.checked_sub(1).map(|i| &nodes[i]) else {
+        return;
+    };
+
+    match node {
+        Node::Leaf(positions) => cells.extend(
+            positions
+                .iter()
+                .map(|p| Cell::new(origin.row(), origin.column()) + *p),
+        ),
+        Node::Branch(level, [nw, ne, sw, se]) => {
+            let half = 1 << (level - 1);
+            let quadrant = |dr, dc| Position::new(origin.row() + dr, origin.column() + dc);
+            expand(nodes, *nw, quadrant(0, 0), cells);
+            expand(nodes, *ne, quadrant(0, half), cells);
+            expand(nodes, *sw, quadrant(half, 0), cells);
+            expand(nodes, *se, quadrant(half, half), cells);
+        }
+    }
+}
+
+impl TryFrom<&str> for Macrocell {
+    type Error = FormatError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut macrocell = Macrocell::default();
+        let mut lines = value.lines().map(str::trim).enumerate();
+
+        match lines.next() {
+            Some((_, header)) if header.starts_with(HEADER) => {}
+            Some((_, header)) => return Err(FormatError::InvalidHeader(header.into())),
+            None => return Err(FormatError::InvalidHeader("".into())),
+        }
+
+        let mut nodes = Vec::new();
+        for (li, line) in lines {
+            let line_number = li + 1;
+            if line.is_empty() {
+                continue;
+            } else if let Some(comment) = line.strip_prefix('#') {
+                macrocell.parse_comment(comment)?;
+            } else if line.starts_with(['.', '*', '$']) {
+                nodes.push(parse_leaf(line, line_number)?);
+            } else {
+                nodes.push(parse_branch(line, &nodes, line_number)?);
+            }
+        }
+
+        if let Some(root) = nodes.last() {
+            let half = 1 << (root.level() - 1);
+            expand(
+                &nodes,
+                nodes.len(),
+                Position::new(-half, -half),
+                &mut macrocell.cells,
+            );
+        }
+
+        Ok(macrocell)
+    }
+}
+
+impl TryFrom<&Path> for Macrocell {
+    type Error = FormatError;
+
+    fn try_from(value: &Path) -> Result<Self, Self::Error> {
+        let content = std::fs::read_to_string(value)?;
+        Macrocell::try_from(content.as_str())
+    }
+}
+
+impl From<Macrocell> for Cells {
+    fn from(value: Macrocell) -> Self {
+        value.cells
+    }
+}
+
+impl From<&Cells> for Macrocell {
+    fn from(value: &Cells) -> Self {
+        Self {
+            cells: value.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<&World> for Macrocell {
+    type Error = FormatError;
+
+    /// Only the live cells and rule of an unbounded world can be written: worlds whose
+    /// background is live, with decaying cells, or with bounds, are refused.
+    fn try_from(value: &World) -> Result<Self, Self::Error> {
+        if value.is_background_live() {
+            return Err(FormatError::LiveBackground);
+        }
+        if !value.decaying_cells().is_empty() {
+            return Err(FormatError::Unrepresentable("decaying cells".into()));
+        }
+        if value.bounds().is_defined() {
+            return Err(FormatError::Unrepresentable("bounds".into()));
+        }
+
+        Ok(Self {
+            cells: value.live_cells().clone(),
+            rule: Some(*value.rule()),
+            metadata: Metadata::default(),
+        })
+    }
+}
+
+/// Accumulates the distinct nodes of a quadtree, children before their parents.
+#[derive(Default)]
+struct Builder {
+    nodes: Vec<Node>,
+    indices: HashMap<Node, usize>,
+}
+
+impl Builder {
+    fn build(&mut self, cells: Vec<Cell>, level: u32, origin: Position) -> usize {
+        if cells.is_empty() {
+            return 0;
+        }
+
+        let node = if level == LEAF_LEVEL {
+            let mut positions = cells
+                .iter()
+                .map(|c| Position::new(c.row() - origin.row(), c.column() - origin.column()))
+                .collect::<Vec<_>>();
+            positions.sort_by_key(|p| (p.row(), p.column()));
+            Node::Leaf(positions)
+        } else {
+            let half = 1 << (level - 1);
+            let mut quadrants: [Vec<Cell>; 4] = Default::default();
+            for cell in cells {
+                let south = cell.row() >= origin.row() + half;
+                let east = cell.column() >= origin.column() + half;
+                quadrants[usize::from(south) * 2 + usize::from(east)].push(cell);
+            }
+            let [nw, ne, sw, se] = quadrants;
+            let quadrant = |dr, dc| Position::new(origin.row() + dr, origin.column() + dc);
+            Node::Branch(
+                level,
+                [
+                    self.build(nw, level - 1, quadrant(0, 0)),
+                    self.build(ne, level - 1, quadrant(0, half)),
+                    self.build(sw, level - 1, quadrant(half, 0)),
+                    self.build(se, level - 1, quadrant(half, half)),
+                ],
+            )
+        };
+
+        if let Some(index) = self.indices.get(&node) {
+            return *index;
+        }
+        self.nodes.push(node.clone());
+        self.indices.insert(node, self.nodes.len());
+        self.nodes.len()
+    }
+}
+
+impl std::fmt::Display for Macrocell {
+    /// Fails for cells too far from the origin for any root to hold.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} (life {})", HEADER, env!("CARGO_PKG_VERSION"))?;
+        if let Some(rule) = &self.rule {
+            writeln!(f, "#R {}", rule)?;
+        }
+        if let Some(name) = self.metadata.name() {
+            writeln!(f, "#N {}", name)?;
+        }
+        if let Some(author) = self.metadata.author() {
+            writeln!(f, "#O {}", author)?;
+        }
+        for comment in self.metadata.comments() {
+            writeln!(f, "#C {}", comment)?;
+        }
+
+        let level = self.level().ok_or(std::fmt::Error)?;
+        let half = 1 << (level - 1);
+        let mut builder = Builder::default();
+        builder.build(
+            self.cells.iter().copied().collect(),
+            level,
+            Position::new(-half, -half),
+        );
+
+        for node in builder.nodes {
+            match node {
+                Node::Leaf(positions) => {
+                    let mut line = String::new();
+                    let last_row = positions.last().map_or(0, |p| p.row());
+                    for row in 0..=last_row {
+                        let mut column = 0;
+                        for p in positions.iter().filter(|p| p.row() == row) {
+                            line.extend(std::iter::repeat_n('.', (p.column() - column) as usize));
+                            line.push('*');
+                            column = p.column() + 1;
+                        }
+                        line.push('$');
+                    }
+                    writeln!(f, "{}", line)?;
+                }
+                Node::Branch(level, [nw, ne, sw, se]) => {
+                    writeln!(f, "{} {} {} {} {}", level, nw, ne, sw, se)?
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+mod life_1_05;
+mod life_1_06;
+mod macrocell;
+mod plaintext;
+mod rle;
+
+pub use life_1_05::Life105;
+pub use life_1_06::Life106;
+pub use macrocell::Macrocell;
+pub use plaintext::Plaintext;
+pub use rle::Rle;
+use thiserror::*;
+
+use super::rule::RuleError;
+
+#[derive(Debug, Error)]
+pub enum FormatError {
+    #[error("invalid header: {0}")]
+    InvalidHeader(String),
+
+    #[error("{0}")]
+    InvalidRule(#[from] RuleError),
+
+    #[error("invalid line {0}")]
+    InvalidLine(usize),
+
+    #[error("unexpected character '{0}' on line {1}")]
+    UnexpectedCharacter(char, usize),
+
+    #[error("run too long on line {0}")]
+    RunTooLong(usize),
+
+    #[error("cannot write a world whose background is live")]
+    LiveBackground,
+
+    #[error("the format cannot represent {0}")]
+    Unrepresentable(String),
+
+    #[error("unsupported file extension '{0}'")]
+    UnsupportedExtension(String),
+
+    #[error("io error: {0}")]
+    FileError(#[from] std::io::Error),
+}
+
+/// Descriptive information carried alongside the cells of a pattern file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Metadata {
+    name: Option<String>,
+    author: Option<String>,
+    comments: Vec<String>,
+}
+
+impl Metadata {
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    pub fn comments(&self) -> &[String] {
+        &self.comments
+    }
+}
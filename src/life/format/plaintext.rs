@@ -0,0 +1,129 @@
+use std::path::Path;
+
+use super::{FormatError, Metadata};
+use crate::life::{cell::Cell, cells::Cells};
+
+/// A pattern in [Plaintext](https://conwaylife.com/wiki/Plaintext) (`.cells`) format.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Plaintext {
+    cells: Cells,
+    metadata: Metadata,
+}
+
+impl Plaintext {
+    pub fn cells(&self) -> &Cells {
+        &self.cells
+    }
+
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), FormatError> {
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    fn parse_comment(&mut self, comment: &str) {
+        if let Some(name) = comment.strip_prefix("Name:") {
+            self.metadata.name = Some(name.trim().into());
+        } else if let Some(author) = comment.strip_prefix("Author:") {
+            self.metadata.author = Some(author.trim().into());
+        } else {
+            self.metadata.comments.push(comment.trim().into());
+        }
+    }
+}
+
+impl TryFrom<&str> for Plaintext {
+    type Error = FormatError;
+
+    /// Rows may be of differing lengths; short rows are treated as padded with dead cells.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut plaintext = Plaintext::default();
+        let mut row = 0;
+
+        for (li, line) in value.lines().map(str::trim_end).enumerate() {
+            if let Some(comment) = line.strip_prefix('!') {
+                plaintext.parse_comment(comment);
+                continue;
+            }
+
+            for (ci, c) in line.chars().enumerate() {
+                match c {
+                    '.' => {}
+                    'O' | '*' => {
+                        plaintext.cells.insert(Cell::new(row, ci as isize));
+                    }
+                    c => return Err(FormatError::UnexpectedCharacter(c, li + 1)),
+                }
+            }
+            row += 1;
+        }
+
+        Ok(plaintext)
+    }
+}
+
+impl TryFrom<&Path> for Plaintext {
+    type Error = FormatError;
+
+    fn try_from(value: &Path) -> Result<Self, Self::Error> {
+        let content = std::fs::read_to_string(value)?;
+        Plaintext::try_from(content.as_str())
+    }
+}
+
+impl From<Plaintext> for Cells {
+    fn from(value: Plaintext) -> Self {
+        value.cells
+    }
+}
+
+impl From<&Cells> for Plaintext {
+    fn from(value: &Cells) -> Self {
+        Self {
+            cells: value.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl std::fmt::Display for Plaintext {
+    /// Cells are written relative to the top left corner of their bounds.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Metadata {
+            name,
+            author,
+            comments,
+        } = &self.metadata;
+
+        if let Some(name) = name {
+            writeln!(f, "!Name: {}", name)?;
+        }
+        if let Some(author) = author {
+            writeln!(f, "!Author: {}", author)?;
+        }
+        for comment in comments {
+            writeln!(f, "!{}", comment)?;
+        }
+
+        let bounds = self.cells.bounds();
+        for r in bounds.rows().clone() {
+            let row = bounds
+                .columns()
+                .clone()
+                .map(|c| {
+                    if self.cells.contains(&Cell::new(r, c)) {
+                        'O'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            writeln!(f, "{}", row)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,456 @@
+use std::{collections::BTreeMap, path::Path};
+
+use super::{FormatError, Metadata};
+use crate::life::{
+    bounds::Bounds, cell::Cell, cells::Cells, position::Position, rule::Rule, states::CellStates,
+    topology::Topology, world::World,
+};
+
+const MAX_LINE_LENGTH: usize = 70;
+const STATES_PER_PREFIX: usize = 24;
+
+/// A pattern in [Run Length Encoded](https://conwaylife.com/wiki/Run_Length_Encoded) format.
+///
+/// Multi-state patterns use Golly's alphabet: `.` is dead, `A` is live, and `B` to `X` are the
+/// following states, with the prefixes `p` to `y` extending the range beyond 24.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Rle {
+    cells: Cells,
+    decaying_cells: CellStates,
+    rule: Option<Rule>,
+    bounds: Option<Bounds>,
+    topology: Topology,
+    metadata: Metadata,
+}
+
+impl Rle {
+    pub fn cells(&self) -> &Cells {
+        &self.cells
+    }
+
+    pub fn decaying_cells(&self) -> &CellStates {
+        &self.decaying_cells
+    }
+
+    pub fn rule(&self) -> Option<&Rule> {
+        self.rule.as_ref()
+    }
+
+    pub fn bounds(&self) -> Option<&Bounds> {
+        self.bounds.as_ref()
+    }
+
+    pub fn topology(&self) -> &Topology {
+        &self.topology
+    }
+
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), FormatError> {
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    /// Reads the position of the pattern, and of its bounds when they are not centred on the
+    /// origin, from an extended RLE comment, along with the metadata comments.
+    fn parse_comment(
+        &mut self,
+        comment: &str,
+        offset: &mut Position,
+        origin: &mut Option<Position>,
+    ) {
+        if let Some(extended) = comment.strip_prefix("CXRLE") {
+            let item = |key: &str| {
+                extended
+                    .split_whitespace()
+                    .find_map(|item| item.strip_prefix(key))
+                    .and_then(parse_position)
+            };
+            if let Some(position) = item("Pos=") {
+                *offset = position;
+            }
+            if let Some(position) = item("Origin=") {
+                *origin = Some(position);
+            }
+        } else if let Some(name) = comment.strip_prefix('N') {
+            self.metadata.name = Some(name.trim().into());
+        } else if let Some(author) = comment.strip_prefix('O') {
+            self.metadata.author = Some(author.trim().into());
+        } else if let Some(text) = comment
+            .strip_prefix('C')
+            .or_else(|| comment.strip_prefix('c'))
+        {
+            self.metadata.comments.push(text.trim().into());
+        }
+    }
+
+    fn parse_header(&mut self, line: &str) -> Result<(), FormatError> {
+        let invalid = || FormatError::InvalidHeader(line.into());
+
+        // The rule may itself contain commas, so it is taken as the remainder of the line.
+        let (dimensions, rule) = match line.split_once("rule") {
+            Some((dimensions, rule)) => {
+                let rule = rule.trim_start().strip_prefix('=').ok_or_else(invalid)?;
+                (dimensions, Some(rule.trim()))
+            }
+            None => (line, None),
+        };
+
+        for item in dimensions.split(',').filter(|i| !i.trim().is_empty()) {
+            let (key, value) = item.split_once('=').ok_or_else(invalid)?;
+            match key.trim() {
+                "x" | "y" => {
+                    value.trim().parse::<usize>().map_err(|_| invalid())?;
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        if let Some(rule) = rule {
+            let (rule, grid) = match rule.split_once(':') {
+                Some((rule, grid)) => (rule, Some(grid)),
+                None => (rule, None),
+            };
+            self.rule = Some(rule.parse()?);
+            if let Some(grid) = grid {
+                let (topology, bounds) = parse_grid(grid).ok_or_else(invalid)?;
+                self.topology = topology;
+                self.bounds = Some(bounds);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a Golly bounded grid suffix, such as `P80,60` or `T80,60`, whose grid is centred on
+/// the origin.
+fn parse_grid(s: &str) -> Option<(Topology, Bounds)> {
+    let (topology, width, height) = Topology::parse_grid(s)?;
+    Some((topology, centred_bounds(width, height)))
+}
+
+/// The move from the cells' place within `bounds` to their place within Golly's grid of the same
+/// size, centred on the origin.
+fn grid_shift(bounds: &Bounds) -> Position {
+    let centred = centred_bounds(bounds.width(), bounds.height());
+    Position::new(
+        centred.rows().start() - bounds.rows().start(),
+        centred.columns().start() - bounds.columns().start(),
+    )
+}
+
+fn centred_bounds(width: usize, height: usize) -> Bounds {
+    let (width, height) = (width as isize, height as isize);
+    let (top, left) = (-(height / 2), -(width / 2));
+    Bounds::new(top..=top + height - 1, left..=left + width - 1)
+}
+
+/// The state named by `letter`, `A` to `X`, after an optional `p` to `y` prefix.
+fn parse_state(prefix: Option<char>, letter: char) -> Option<u8> {
+    let offset = prefix.map_or(0, |p| (p as usize - 'p' as usize + 1) * STATES_PER_PREFIX);
+    u8::try_from(offset + letter as usize - 'A' as usize + 1).ok()
+}
+
+fn state_tag(state: u8, is_multistate: bool) -> String {
+    match (state, is_multistate) {
+        (0, false) => "b".into(),
+        (_, false) => "o".into(),
+        (0, true) => ".".into(),
+        (s, true) => {
+            let index = s as usize - 1;
+            let letter = char::from(b'A' + (index % STATES_PER_PREFIX) as u8);
+            match index / STATES_PER_PREFIX {
+                0 => letter.to_string(),
+                p => format!("{}{}", char::from(b'p' + p as u8 - 1), letter),
+            }
+        }
+    }
+}
+
+fn parse_position(s: &str) -> Option<Position> {
+    let (column, row) = s.split_once(',')?;
+    Some(Position::new(row.parse().ok()?, column.parse().ok()?))
+}
+
+impl TryFrom<&str> for Rle {
+    type Error = FormatError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut rle = Rle::default();
+        let mut offset = Position::new(0, 0);
+        let mut origin = None;
+        let mut is_header_expected = true;
+        let mut cells = Vec::new();
+        let (mut row, mut column) = (0, 0);
+        let mut run = None::<isize>;
+        let mut prefix = None::<char>;
+
+        'lines: for (li, line) in value.lines().map(str::trim).enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(comment) = line.strip_prefix('#') {
+                rle.parse_comment(comment, &mut offset, &mut origin);
+                continue;
+            }
+
+            if is_header_expected && line.starts_with('x') {
+                is_header_expected = false;
+                rle.parse_header(line)?;
+                continue;
+            }
+
+            is_header_expected = false;
+            for c in line.chars() {
+                let unexpected = FormatError::UnexpectedCharacter(c, li + 1);
+                if prefix.is_some() && !c.is_ascii_uppercase() {
+                    return Err(unexpected);
+                }
+
+                let n = run.unwrap_or(1);
+                match c {
+                    '0'..='9' => {
+                        let digit = c.to_digit(10).unwrap() as isize;
+                        let next = run.unwrap_or(0).checked_mul(10);
+                        let next = next.and_then(|run| run.checked_add(digit));
+                        run = Some(next.ok_or(FormatError::RunTooLong(li + 1))?);
+                        continue;
+                    }
+                    'p'..='y' => {
+                        prefix = Some(c);
+                        continue;
+                    }
+                    'b' | '.' => column += n,
+                    'o' | 'A'..='X' => {
+                        let state = match c {
+                            'o' => 1,
+                            c => parse_state(prefix.take(), c).ok_or(unexpected)?,
+                        };
+                        cells.extend((column..column + n).map(|c| (Cell::new(row, c), state)));
+                        column += n;
+                    }
+                    '$' => {
+                        row += n;
+                        column = 0;
+                    }
+                    '!' => break 'lines,
+                    c if c.is_whitespace() => continue,
+                    c => return Err(FormatError::UnexpectedCharacter(c, li + 1)),
+                }
+                run = None;
+            }
+        }
+
+        // Bounds recorded away from the origin are moved back there, with the cells.
+        if let (Some(bounds), Some(origin)) = (&rle.bounds, origin) {
+            let (rows, columns) = (bounds.rows(), bounds.columns());
+            let (dr, dc) = (
+                origin.row() - rows.start(),
+                origin.column() - columns.start(),
+            );
+            rle.bounds = Some(Bounds::new(
+                rows.start() + dr..=rows.end() + dr,
+                columns.start() + dc..=columns.end() + dc,
+            ));
+            offset = Position::new(offset.row() + dr, offset.column() + dc);
+        }
+
+        let (live, decaying): (Vec<_>, Vec<_>) = cells.into_iter().partition(|(_, s)| *s == 1);
+        rle.cells = Cells::from_iter(live.into_iter().map(|(c, _)| c + offset));
+        rle.decaying_cells =
+            CellStates::from_iter(decaying.into_iter().map(|(c, s)| (c + offset, s)));
+        Ok(rle)
+    }
+}
+
+impl TryFrom<&Path> for Rle {
+    type Error = FormatError;
+
+    fn try_from(value: &Path) -> Result<Self, Self::Error> {
+        let content = std::fs::read_to_string(value)?;
+        Rle::try_from(content.as_str())
+    }
+}
+
+impl From<Rle> for Cells {
+    fn from(value: Rle) -> Self {
+        value.cells
+    }
+}
+
+impl From<&Cells> for Rle {
+    fn from(value: &Cells) -> Self {
+        Self {
+            cells: value.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<&World> for Rle {
+    type Error = FormatError;
+
+    /// Worlds whose background is live cannot be written, as the format has no way to say so.
+    fn try_from(value: &World) -> Result<Self, Self::Error> {
+        if value.is_background_live() {
+            return Err(FormatError::LiveBackground);
+        }
+
+        Ok(Self {
+            cells: value.live_cells().clone(),
+            decaying_cells: value.decaying_cells().clone(),
+            rule: Some(*value.rule()),
+            bounds: Some(value.bounds().clone()).filter(Bounds::is_defined),
+            topology: *value.topology(),
+            metadata: Metadata::default(),
+        })
+    }
+}
+
+impl std::fmt::Display for Rle {
+    /// Golly centres bounded grids on the origin, so the cells are written as they lie in the
+    /// centred grid, and bounds lying elsewhere are recorded as the `Origin` of the extended RLE
+    /// comment, their top left, to be moved back when read.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Metadata {
+            name,
+            author,
+            comments,
+        } = &self.metadata;
+
+        if let Some(name) = name {
+            writeln!(f, "#N {}", name)?;
+        }
+        if let Some(author) = author {
+            writeln!(f, "#O {}", author)?;
+        }
+        for comment in comments {
+            writeln!(f, "#C {}", comment)?;
+        }
+
+        let shift = self.bounds.as_ref().map_or(Position::new(0, 0), grid_shift);
+        let cells = Cells::from_iter(self.cells.iter().map(|c| *c + shift));
+        let decaying_cells =
+            CellStates::from_iter(self.decaying_cells.iter().map(|(c, s)| (*c + shift, *s)));
+
+        let mut extent = cells.bounds();
+        decaying_cells.keys().for_each(|c| extent.encompass(c));
+        let (top, left) = if extent.is_defined() {
+            (*extent.rows().start(), *extent.columns().start())
+        } else {
+            (0, 0)
+        };
+        let mut extended = Vec::new();
+        if (top, left) != (0, 0) {
+            extended.push(format!("Pos={},{}", left, top));
+        }
+        if let Some(bounds) = self
+            .bounds
+            .as_ref()
+            .filter(|_| shift != Position::new(0, 0))
+        {
+            let (top, left) = (bounds.rows().start(), bounds.columns().start());
+            extended.push(format!("Origin={},{}", left, top));
+        }
+        if !extended.is_empty() {
+            writeln!(f, "#CXRLE {}", extended.join(" "))?;
+        }
+
+        write!(f, "x = {}, y = {}", extent.width(), extent.height())?;
+        let rule = self.rule.unwrap_or_default();
+        match &self.bounds {
+            Some(bounds) => writeln!(
+                f,
+                ", rule = {}:{}",
+                rule,
+                self.topology.grid(bounds.width(), bounds.height())
+            )?,
+            None => writeln!(f, ", rule = {}", rule)?,
+        }
+
+        let is_multistate = rule.states() > 2 || !decaying_cells.is_empty();
+        let mut rows = BTreeMap::<isize, Vec<(isize, u8)>>::new();
+        cells
+            .iter()
+            .map(|c| (c, 1))
+            .chain(decaying_cells.iter().map(|(c, s)| (c, *s)))
+            .for_each(|(c, s)| rows.entry(c.row()).or_default().push((c.column(), s)));
+
+        let mut tokens = Vec::new();
+        let mut previous_row = top;
+        for (row, mut states) in rows {
+            if row > previous_row {
+                tokens.push(run(row - previous_row, "$"));
+            }
+            previous_row = row;
+
+            states.sort();
+            let mut column = left;
+            let mut pending = None::<(u8, isize)>;
+            for (c, state) in states {
+                if c > column {
+                    flush(&mut tokens, pending.replace((0, c - column)), is_multistate);
+                }
+                match &mut pending {
+                    Some((s, n)) if *s == state => *n += 1,
+                    _ => flush(&mut tokens, pending.replace((state, 1)), is_multistate),
+                }
+                column = c + 1;
+            }
+            flush(&mut tokens, pending, is_multistate);
+        }
+        tokens.push("!".into());
+
+        let mut line = String::new();
+        for token in tokens {
+            if line.len() + token.len() > MAX_LINE_LENGTH {
+                writeln!(f, "{}", line)?;
+                line.clear();
+            }
+            line.push_str(&token);
+        }
+        writeln!(f, "{}", line)
+    }
+}
+
+fn flush(tokens: &mut Vec<String>, pending: Option<(u8, isize)>, is_multistate: bool) {
+    if let Some((state, count)) = pending {
+        tokens.push(run(count, &state_tag(state, is_multistate)));
+    }
+}
+
+fn run(count: isize, tag: &str) -> String {
+    if count == 1 {
+        tag.to_string()
+    } else {
+        format!("{}{}", count, tag)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rule_may_contain_commas() {
+        let rle = Rle::try_from("x = 1, y = 1, rule = R5,C0,M1,S34..58,B34..45,NM\no!")
+            .expect("valid rle");
+        assert_eq!(
+            rle.rule().map(|r| r.to_string()),
+            Some("R5,C0,M1,S34..58,B34..45,NM".into())
+        );
+    }
+
+    #[test]
+    fn runs_are_not_split_across_lines() {
+        let cells = Cells::from_iter((0..100).step_by(2).map(|c| Cell::new(0, c)));
+        let text = Rle::from(&cells).to_string();
+        let body = text.lines().skip(1).collect::<Vec<_>>();
+        assert!(body.iter().all(|l| l.len() <= MAX_LINE_LENGTH));
+        assert_eq!(body.concat(), format!("{}o!", "ob".repeat(49)));
+    }
+}
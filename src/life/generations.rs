@@ -1,28 +1,275 @@
-use std::collections::HashSet;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    hash::{DefaultHasher, Hash, Hasher},
+};
 
-use super::{cells::Cells, world::World};
+use super::{cycle::Cycle, engine::Engine, position::Position, world::World};
 
-#[derive(Debug)]
-pub struct Generations {
-    current: World,
-    previous: HashSet<Cells>,
+/// How much of its past [Generations] remembers in order to spot repeats, trading memory for
+/// time. Each generation is remembered by a hash alone, and the engine itself is kept every
+/// `checkpoint_interval` generations; a repeated hash is confirmed exactly by stepping the
+/// checkpoint before it on to the generation it stands for. Only the last `window` generations
+/// are remembered, so memory is bounded, but cycles of longer periods go unnoticed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct History {
+    window: u64,
+    checkpoint_interval: u64,
 }
 
-impl Generations {
-    pub fn new(world: World) -> Self {
+impl History {
+    pub fn new(window: u64, checkpoint_interval: u64) -> Self {
         Self {
-            current: world,
-            previous: HashSet::default(),
+            window: window.max(1),
+            checkpoint_interval: checkpoint_interval.max(1),
         }
     }
 
-    pub fn current(&self) -> &World {
+    /// The number of generations remembered, and so the longest period that can be spotted.
+    pub fn window(&self) -> u64 {
+        self.window
+    }
+
+    /// The number of generations between checkpoints, and so the most generations stepped to
+    /// confirm a repeat.
+    pub fn checkpoint_interval(&self) -> u64 {
+        self.checkpoint_interval
+    }
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self::new(1 << 16, 1 << 10)
+    }
+}
+
+/// A generation remembered by the hashes of its snapshot and, if it has one, of its shape.
+#[derive(Debug)]
+struct Memory {
+    generation: u64,
+    snapshot: u64,
+    shape: Option<u64>,
+}
+
+/// The generations of an engine, stepped until one repeats. Along the way, the first repeat of
+/// the cells, or of their shape elsewhere, reveals the [Cycle] they have entered, so that
+/// oscillators and spaceships are recognised as soon as they first come round again.
+#[derive(Debug)]
+pub struct Generations<E: Engine = World> {
+    current: E,
+    generation: u64,
+    start: u64,
+    history: History,
+    memories: VecDeque<Memory>,
+    snapshots: HashMap<u64, Vec<u64>>,
+    shapes: HashMap<u64, Vec<u64>>,
+    checkpoints: BTreeMap<u64, E>,
+    cycle: Option<Cycle>,
+}
+
+impl<E: Engine> Generations<E> {
+    pub fn new(engine: E) -> Self {
+        let mut generations = Self {
+            current: engine,
+            generation: 0,
+            start: 0,
+            history: History::default(),
+            memories: VecDeque::new(),
+            snapshots: HashMap::new(),
+            shapes: HashMap::new(),
+            checkpoints: BTreeMap::new(),
+            cycle: None,
+        };
+        generations.remember();
+        generations
+    }
+
+    pub fn with_history(&mut self, history: &History) {
+        self.history = *history;
+        self.forget();
+    }
+
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    pub fn current(&self) -> &E {
         &self.current
     }
 
-    pub fn next_generation(&mut self) -> Option<&World> {
-        self.current.next_generation();
-        let is_unique = self.previous.insert(self.current.live_cells().clone());
+    /// The number of generations since the first.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// The cycle the generations have entered, once it has been seen to repeat.
+    pub fn cycle(&self) -> Option<&Cycle> {
+        self.cycle.as_ref()
+    }
+
+    /// Steps to the next generation, unless it repeats one stepped to before.
+    pub fn next_generation(&mut self) -> Option<&E> {
+        self.current.step();
+        self.generation += 1;
+        let is_unique = self.remember();
         is_unique.then_some(&self.current)
     }
+
+    /// Jumps `generations` ahead. The generations jumped over are unknown, so the generations
+    /// before are forgotten, and repeats are only spotted from the one jumped to.
+    pub fn advance(&mut self, generations: u64) -> &E {
+        if generations > 0 {
+            self.current.step_n(generations);
+            self.generation += generations;
+            self.start = self.generation;
+            self.memories.clear();
+            self.snapshots.clear();
+            self.shapes.clear();
+            self.checkpoints.clear();
+            self.remember();
+        }
+        &self.current
+    }
+
+    /// Remembers the current generation, noting the cycle it completes, if any; returns whether
+    /// it differs from every remembered generation but the first.
+    fn remember(&mut self) -> bool {
+        let generation = self.generation;
+        if (generation - self.start).is_multiple_of(self.history.checkpoint_interval) {
+            self.checkpoints.insert(generation, self.current.clone());
+        }
+
+        let snapshot = self.current.snapshot();
+        let snapshot_hash = hash(&snapshot);
+        let last_seen = self
+            .snapshots
+            .get(&snapshot_hash)
+            .into_iter()
+            .flatten()
+            .rev()
+            .copied()
+            .find(|seen| self.generation_at(*seen).snapshot() == snapshot);
+
+        let shape = match self.cycle {
+            Some(_) => None,
+            None => self.current.normalised_snapshot(),
+        };
+        let shape = shape.map(|(shape, origin)| {
+            let shape_hash = hash(&shape);
+            let seen = self
+                .shapes
+                .get(&shape_hash)
+                .into_iter()
+                .flatten()
+                .find_map(|seen| {
+                    let (seen_shape, seen_origin) =
+                        self.generation_at(*seen).normalised_snapshot()?;
+                    (seen_shape == shape).then_some((*seen, seen_origin))
+                });
+            if let Some((entry, entry_origin)) = seen {
+                let displacement = Position::new(
+                    origin.row() - entry_origin.row(),
+                    origin.column() - entry_origin.column(),
+                );
+                self.cycle = Some(Cycle::new(entry, generation - entry, displacement));
+            }
+            shape_hash
+        });
+
+        if self.cycle.is_none()
+            && shape.is_none()
+            && let Some(entry) = last_seen
+        {
+            self.cycle = Some(Cycle::new(entry, generation - entry, Position::new(0, 0)));
+        }
+
+        self.snapshots
+            .entry(snapshot_hash)
+            .or_default()
+            .push(generation);
+        if let Some(shape_hash) = shape {
+            self.shapes.entry(shape_hash).or_default().push(generation);
+        }
+        self.memories.push_back(Memory {
+            generation,
+            snapshot: snapshot_hash,
+            shape,
+        });
+        self.forget();
+
+        last_seen.is_none_or(|seen| seen == self.start)
+    }
+
+    /// Forgets the generations that have fallen out of the history's window, and the shapes
+    /// once the cycle is known, keeping the checkpoint before the earliest generation left.
+    fn forget(&mut self) {
+        if self.cycle.is_some() {
+            self.shapes.clear();
+        }
+
+        let earliest = (self.generation + 1).saturating_sub(self.history.window);
+        while let Some(memory) = self.memories.front()
+            && memory.generation < earliest
+        {
+            let memory = self.memories.pop_front().expect("memory");
+            forget(&mut self.snapshots, memory.snapshot, memory.generation);
+            if let Some(shape) = memory.shape {
+                forget(&mut self.shapes, shape, memory.generation);
+            }
+        }
+
+        let first_needed = self
+            .checkpoints
+            .range(..=earliest)
+            .next_back()
+            .map(|(g, _)| *g);
+        if let Some(first_needed) = first_needed {
+            self.checkpoints = self.checkpoints.split_off(&first_needed);
+        }
+    }
+
+    /// The engine at a remembered `generation`, stepped on from the checkpoint before it.
+    fn generation_at(&self, generation: u64) -> E {
+        let (checkpoint, engine) = self
+            .checkpoints
+            .range(..=generation)
+            .next_back()
+            .expect("checkpoint before every remembered generation");
+        let mut engine = engine.clone();
+        engine.step_n(generation - checkpoint);
+        engine
+    }
+}
+
+fn hash(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn forget(generations_by_hash: &mut HashMap<u64, Vec<u64>>, hash: u64, generation: u64) {
+    if let Some(generations) = generations_by_hash.get_mut(&hash) {
+        generations.retain(|g| *g != generation);
+        if generations.is_empty() {
+            generations_by_hash.remove(&hash);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::life::pattern::Pattern;
+
+    #[test]
+    fn memory_is_bounded_by_the_window() {
+        let world = World::try_from(&Pattern::GosperGliderGun).unwrap();
+        let mut generations = Generations::new(world);
+        generations.with_history(&History::new(100, 30));
+        (0..500).for_each(|_| {
+            generations.next_generation();
+        });
+        assert_eq!(generations.memories.len(), 100);
+        assert_eq!(generations.snapshots.values().flatten().count(), 100);
+        assert!(generations.checkpoints.len() <= 5);
+    }
 }
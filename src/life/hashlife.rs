@@ -0,0 +1,565 @@
+use std::collections::HashMap;
+
+use thiserror::*;
+
+use super::{
+    bounds::Bounds,
+    cell::Cell,
+    cells::Cells,
+    engine::{Engine, normalised},
+    neighbourhood::Neighbourhood,
+    position::Position,
+    rule::Rule,
+};
+
+/// The number of nodes beyond which the memoised nodes are discarded, keeping only those making
+/// up the universe when stepping.
+const MAX_NODES: usize = 1 << 22;
+
+/// The level of the largest root, whose size and corners fit the coordinates of cells.
+const MAX_LEVEL: u8 = 62;
+
+/// The largest jump, as a power of two, leaving the root room to reach level `j + 3`.
+const MAX_JUMP: u8 = MAX_LEVEL - 3;
+
+#[derive(Debug, Error)]
+pub enum HashLifeError {
+    #[error("rule '{0}' is not supported by hashlife")]
+    UnsupportedRule(Rule),
+
+    #[error("the universe has outgrown the coordinates of its cells")]
+    Overflow,
+}
+
+type NodeId = u32;
+
+const DEAD: NodeId = 0;
+const ALIVE: NodeId = 1;
+
+/// A square of `2^level` cells, made of four quadrants one level down.
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    level: u8,
+    children: [NodeId; 4],
+    population: u64,
+}
+
+/// A [HashLife](https://conwaylife.com/wiki/HashLife) universe: a quadtree whose identical
+/// nodes are shared, and whose nodes remember their own futures, so that repetitive patterns can
+/// be advanced by enormous numbers of generations at once.
+///
+/// Only two-state rules of the eight cell neighbourhood without B0 are supported, and the
+/// universe is unbounded.
+pub struct HashLife {
+    rule: Rule,
+    nodes: Vec<Node>,
+    index: HashMap<[NodeId; 4], NodeId>,
+    results: HashMap<(NodeId, u8), NodeId>,
+    empties: Vec<NodeId>,
+    root: NodeId,
+    origin: Position,
+    generation: u64,
+}
+
+impl HashLife {
+    pub fn new(rule: &Rule) -> Result<Self, HashLifeError> {
+        if !Self::supports(rule) {
+            return Err(HashLifeError::UnsupportedRule(*rule));
+        }
+
+        let mut hashlife = Self {
+            rule: *rule,
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            results: HashMap::new(),
+            empties: Vec::new(),
+            root: DEAD,
+            origin: Position::new(0, 0),
+            generation: 0,
+        };
+        hashlife.reset();
+        Ok(hashlife)
+    }
+
+    pub fn supports(rule: &Rule) -> bool {
+        rule.states() == 2 && rule.larger_than_life().is_none() && !rule.is_born(0)
+    }
+
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// The number of generations stepped since the cells were last set.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn population(&self) -> u64 {
+        self.nodes[self.root as usize].population
+    }
+
+    /// Replaces the universe's cells, keeping the memoised nodes unless there are too many.
+    pub fn with_cells(&mut self, cells: &Cells) {
+        if self.nodes.len() > MAX_NODES {
+            self.reset();
+        }
+
+        let bounds = cells.bounds();
+        let (origin, size) = if bounds.is_defined() {
+            let origin = Position::new(*bounds.rows().start(), *bounds.columns().start());
+            (origin, bounds.width().max(bounds.height()))
+        } else {
+            (Position::new(0, 0), 1)
+        };
+        let level = (size.next_power_of_two().trailing_zeros() as u8).max(2);
+
+        let cells = cells.iter().copied().collect::<Vec<_>>();
+        self.root = self.build(cells, level, origin);
+        self.origin = origin;
+        self.generation = 0;
+    }
+
+    pub fn cells(&self) -> Cells {
+        let mut cells = Vec::new();
+        self.collect(self.root, self.origin, &|_, _| true, &mut cells);
+        Cells::from_iter(cells)
+    }
+
+    /// The live cells within `rect`, skipping the nodes lying wholly outside it.
+    pub fn cells_in(&self, rect: &Bounds) -> Cells {
+        let overlaps = |origin: Position, size: isize| {
+            origin.row() <= *rect.rows().end()
+                && origin.row() + size > *rect.rows().start()
+                && origin.column() <= *rect.columns().end()
+                && origin.column() + size > *rect.columns().start()
+        };
+        let mut cells = Vec::new();
+        self.collect(self.root, self.origin, &overlaps, &mut cells);
+        Cells::from_iter(cells)
+    }
+
+    /// Sets the state of `cell`, enlarging the universe to reach it. Only the nodes on the path
+    /// to the cell are replaced.
+    pub fn with_cell(&mut self, cell: &Cell, is_live: bool) {
+        if self.level() < 2 {
+            self.root = self.empty(2);
+        }
+        while !self.contains(cell) {
+            self.expand()
+                .expect("cells within the coordinates of the universe");
+        }
+        let leaf = if is_live { ALIVE } else { DEAD };
+        self.root = self.with_leaf(self.root, self.origin, cell, leaf);
+    }
+
+    /// Advances the universe by `generations`, one power of two at a time; powers beyond
+    /// [MAX_JUMP] are made as several of the largest jump. Fails, at the generation reached, once
+    /// the universe would outgrow the coordinates of its cells.
+    pub fn step(&mut self, generations: u64) -> Result<(), HashLifeError> {
+        for j in (0..u64::BITS as u8).filter(|j| generations & 1 << j != 0) {
+            let (j, jumps) = (j.min(MAX_JUMP), 1u64 << j.saturating_sub(MAX_JUMP));
+            for _ in 0..jumps {
+                while self.level() < j + 3 || !self.is_padded() {
+                    self.expand()?;
+                }
+                let half = 1 << (self.level() - 2);
+                self.root = self.result(self.root, j);
+                self.origin = Position::new(self.origin.row() + half, self.origin.column() + half);
+                self.generation += 1 << j;
+                if self.nodes.len() > MAX_NODES {
+                    self.collect_garbage();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Discards the memoised results and the nodes that are no longer part of the universe.
+    fn collect_garbage(&mut self) {
+        let (root, nodes) = (self.root, std::mem::take(&mut self.nodes));
+        self.reset();
+        let mut copies = HashMap::new();
+        self.root = self.copy(&nodes, root, &mut copies);
+    }
+
+    /// The node `id` of the discarded `nodes`, joined afresh.
+    fn copy(&mut self, nodes: &[Node], id: NodeId, copies: &mut HashMap<NodeId, NodeId>) -> NodeId {
+        if id == DEAD || id == ALIVE {
+            return id;
+        }
+        if let Some(copy) = copies.get(&id) {
+            return *copy;
+        }
+
+        let children = nodes[id as usize]
+            .children
+            .map(|child| self.copy(nodes, child, copies));
+        let copy = self.join(children);
+        copies.insert(id, copy);
+        copy
+    }
+
+    fn reset(&mut self) {
+        self.nodes = vec![
+            Node {
+                level: 0,
+                children: [DEAD; 4],
+                population: 0,
+            },
+            Node {
+                level: 0,
+                children: [DEAD; 4],
+                population: 1,
+            },
+        ];
+        self.index.clear();
+        self.results.clear();
+        self.empties = vec![DEAD];
+        self.root = DEAD;
+    }
+
+    fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id as usize]
+    }
+
+    fn level(&self) -> u8 {
+        self.node(self.root).level
+    }
+
+    fn join(&mut self, children: [NodeId; 4]) -> NodeId {
+        if let Some(id) = self.index.get(&children) {
+            return *id;
+        }
+
+        let node = Node {
+            level: self.node(children[0]).level + 1,
+            children,
+            population: children.iter().map(|c| self.node(*c).population).sum(),
+        };
+        let id = self.nodes.len() as NodeId;
+        self.nodes.push(node);
+        self.index.insert(children, id);
+        id
+    }
+
+    fn empty(&mut self, level: u8) -> NodeId {
+        while self.empties.len() <= level as usize {
+            let empty = *self.empties.last().unwrap();
+            let next = self.join([empty; 4]);
+            self.empties.push(next);
+        }
+        self.empties[level as usize]
+    }
+
+    fn build(&mut self, cells: Vec<Cell>, level: u8, origin: Position) -> NodeId {
+        if cells.is_empty() {
+            return self.empty(level);
+        }
+        if level == 0 {
+            return ALIVE;
+        }
+
+        let half = 1 << (level - 1);
+        let mut quadrants: [Vec<Cell>; 4] = Default::default();
+        for cell in cells {
+            let south = cell.row() >= origin.row() + half;
+            let east = cell.column() >= origin.column() + half;
+            quadrants[usize::from(south) * 2 + usize::from(east)].push(cell);
+        }
+        let [nw, ne, sw, se] = quadrants;
+        let quadrant = |dr, dc| Position::new(origin.row() + dr, origin.column() + dc);
+        let children = [
+            self.build(nw, level - 1, quadrant(0, 0)),
+            self.build(ne, level - 1, quadrant(0, half)),
+            self.build(sw, level - 1, quadrant(half, 0)),
+            self.build(se, level - 1, quadrant(half, half)),
+        ];
+        self.join(children)
+    }
+
+    /// Collects the live cells of `id`, descending only into the nodes, given by their origin
+    /// and size, that are wanted.
+    fn collect(
+        &self,
+        id: NodeId,
+        origin: Position,
+        is_wanted: &impl Fn(Position, isize) -> bool,
+        cells: &mut Vec<Cell>,
+    ) {
+        let node = self.node(id);
+        if node.population == 0 || !is_wanted(origin, 1 << node.level) {
+            return;
+        }
+        if node.level == 0 {
+            cells.push(Cell::new(origin.row(), origin.column()));
+            return;
+        }
+
+        let half = 1 << (node.level - 1);
+        let [nw, ne, sw, se] = node.children;
+        let quadrant = |dr, dc| Position::new(origin.row() + dr, origin.column() + dc);
+        self.collect(nw, quadrant(0, 0), is_wanted, cells);
+        self.collect(ne, quadrant(0, half), is_wanted, cells);
+        self.collect(sw, quadrant(half, 0), is_wanted, cells);
+        self.collect(se, quadrant(half, half), is_wanted, cells);
+    }
+
+    fn contains(&self, cell: &Cell) -> bool {
+        let size = 1 << self.level();
+        (self.origin.row()..self.origin.row() + size).contains(&cell.row())
+            && (self.origin.column()..self.origin.column() + size).contains(&cell.column())
+    }
+
+    /// The node `id`, at `origin`, with the leaf at `cell` replaced.
+    fn with_leaf(&mut self, id: NodeId, origin: Position, cell: &Cell, leaf: NodeId) -> NodeId {
+        let node = *self.node(id);
+        if node.level == 0 {
+            return leaf;
+        }
+
+        let half = 1 << (node.level - 1);
+        let south = cell.row() >= origin.row() + half;
+        let east = cell.column() >= origin.column() + half;
+        let quadrant = usize::from(south) * 2 + usize::from(east);
+        let origin = Position::new(
+            origin.row() + if south { half } else { 0 },
+            origin.column() + if east { half } else { 0 },
+        );
+        let mut children = node.children;
+        children[quadrant] = self.with_leaf(children[quadrant], origin, cell, leaf);
+        self.join(children)
+    }
+
+    /// Surrounds the root with empty space, doubling its size about its centre, unless it would
+    /// then reach beyond the coordinates of cells.
+    fn expand(&mut self) -> Result<(), HashLifeError> {
+        let level = self.level();
+        let quarter = 1 << (level - 1);
+        let reach = |n: isize| {
+            n.checked_sub(quarter)
+                .filter(|n| level < MAX_LEVEL && n.checked_add(1 << (level + 1)).is_some())
+        };
+        let (row, column) = match (reach(self.origin.row()), reach(self.origin.column())) {
+            (Some(row), Some(column)) => (row, column),
+            _ => return Err(HashLifeError::Overflow),
+        };
+
+        let empty = self.empty(level - 1);
+        let [nw, ne, sw, se] = self.node(self.root).children;
+        let children = [
+            self.join([empty, empty, empty, nw]),
+            self.join([empty, empty, ne, empty]),
+            self.join([empty, sw, empty, empty]),
+            self.join([se, empty, empty, empty]),
+        ];
+        self.root = self.join(children);
+        self.origin = Position::new(row, column);
+        Ok(())
+    }
+
+    /// Whether every live cell lies within the central quarter of the root's central quarter, so
+    /// that no pattern can escape the root's centre however far it is stepped.
+    fn is_padded(&mut self) -> bool {
+        let centre = self.centre(self.root);
+        let inner = self.centre(centre);
+        self.node(inner).population == self.population()
+    }
+
+    /// The node of half the size at the centre of `id`.
+    fn centre(&mut self, id: NodeId) -> NodeId {
+        let [nw, ne, sw, se] = self.node(id).children;
+        self.join([
+            self.node(nw).children[3],
+            self.node(ne).children[2],
+            self.node(sw).children[1],
+            self.node(se).children[0],
+        ])
+    }
+
+    /// The centre of `id`, `2^j` generations on, where `j` is at most two less than its level.
+    fn result(&mut self, id: NodeId, j: u8) -> NodeId {
+        if let Some(result) = self.results.get(&(id, j)) {
+            return *result;
+        }
+
+        let node = *self.node(id);
+        let result = if node.population == 0 {
+            self.empty(node.level - 1)
+        } else if node.level == 2 {
+            self.base_result(id)
+        } else {
+            let [a, b, c, d] = node.children;
+            let [a_, b_, c_, d_] = [a, b, c, d].map(|n| self.node(n).children);
+            let nine = [
+                a,
+                self.join([a_[1], b_[0], a_[3], b_[2]]),
+                b,
+                self.join([a_[2], a_[3], c_[0], c_[1]]),
+                self.join([a_[3], b_[2], c_[1], d_[0]]),
+                self.join([b_[2], b_[3], d_[0], d_[1]]),
+                c,
+                self.join([c_[1], d_[0], c_[3], d_[2]]),
+                d,
+            ];
+
+            let is_full_speed = j == node.level - 2;
+            let r = nine.map(|n| {
+                if is_full_speed {
+                    self.result(n, j - 1)
+                } else {
+                    self.centre(n)
+                }
+            });
+
+            let quadrants = [
+                self.join([r[0], r[1], r[3], r[4]]),
+                self.join([r[1], r[2], r[4], r[5]]),
+                self.join([r[3], r[4], r[6], r[7]]),
+                self.join([r[4], r[5], r[7], r[8]]),
+            ];
+            let j = j.min(node.level - 3);
+            let children = quadrants.map(|q| self.result(q, j));
+            self.join(children)
+        };
+
+        self.results.insert((id, j), result);
+        result
+    }
+
+    /// The central two by two cells of a four by four node, one generation on.
+    fn base_result(&mut self, id: NodeId) -> NodeId {
+        let mut grid = [[false; 4]; 4];
+        let node = *self.node(id);
+        for (q, quadrant) in node.children.iter().enumerate() {
+            for (i, leaf) in self.node(*quadrant).children.iter().enumerate() {
+                grid[(q / 2) * 2 + i / 2][(q % 2) * 2 + i % 2] = *leaf == ALIVE;
+            }
+        }
+
+        let is_live = |c: &Cell| grid[c.row() as usize][c.column() as usize];
+        let next = [(1, 1), (1, 2), (2, 1), (2, 2)].map(|(r, c)| {
+            let cell = Cell::new(r, c);
+            let neighbourhood = Neighbourhood::of(&cell, is_live);
+            let is_next_live = if is_live(&cell) {
+                self.rule.survives_with(neighbourhood)
+            } else {
+                self.rule.is_born_with(neighbourhood)
+            };
+            if is_next_live { ALIVE } else { DEAD }
+        });
+        self.join(next)
+    }
+}
+
+impl std::fmt::Debug for HashLife {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HashLife")
+            .field("rule", &self.rule)
+            .field("nodes", &self.nodes.len())
+            .field("generation", &self.generation)
+            .field("population", &self.population())
+            .finish()
+    }
+}
+
+impl Clone for HashLife {
+    /// Clones the universe's cells and rule, leaving the memoised nodes behind.
+    fn clone(&self) -> Self {
+        let mut hashlife = Self::new(&self.rule).expect("supported rule");
+        hashlife.with_cells(&self.cells());
+        hashlife.generation = self.generation;
+        hashlife
+    }
+}
+
+impl Engine for HashLife {
+    type Snapshot = Cells;
+
+    fn step(&mut self) {
+        Engine::step_n(self, 1);
+    }
+
+    /// Jumps straight to the generation, a power of two at a time.
+    fn step_n(&mut self, generations: u64) {
+        HashLife::step(self, generations).expect("universe within the coordinates of its cells");
+    }
+
+    fn population(&self) -> usize {
+        HashLife::population(self) as usize
+    }
+
+    fn bounding_box(&self) -> Bounds {
+        self.cells().bounds()
+    }
+
+    fn cells_in(&self, rect: &Bounds) -> Cells {
+        HashLife::cells_in(self, rect)
+    }
+
+    fn set_cell(&mut self, cell: &Cell) {
+        self.with_cell(cell, true);
+    }
+
+    fn clear_cell(&mut self, cell: &Cell) {
+        self.with_cell(cell, false);
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.cells()
+    }
+
+    fn normalised_snapshot(&self) -> Option<(Self::Snapshot, Position)> {
+        Some(normalised(&self.cells()))
+    }
+}
+
+impl From<&HashLife> for Cells {
+    fn from(value: &HashLife) -> Self {
+        value.cells()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn cells_round_trip() {
+        let cells = Cells::from_iter([Cell::new(-3, 5), Cell::new(0, 0), Cell::new(7, -2)]);
+        let mut hashlife = HashLife::new(&Rule::default()).unwrap();
+        hashlife.with_cells(&cells);
+        assert_eq!(hashlife.cells(), cells);
+        assert_eq!(hashlife.population(), 3);
+    }
+
+    #[test]
+    fn nodes_are_shared() {
+        let cells = Cells::from_iter((0..64).step_by(4).map(|c| Cell::new(0, c)));
+        let mut hashlife = HashLife::new(&Rule::default()).unwrap();
+        hashlife.with_cells(&cells);
+        assert!(hashlife.nodes.len() < 20);
+    }
+
+    #[test]
+    fn garbage_is_collected_without_changing_the_universe() {
+        let glider = Cells::from_iter(
+            [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)].map(|(r, c)| Cell::new(r, c)),
+        );
+        let mut hashlife = HashLife::new(&Rule::default()).unwrap();
+        hashlife.with_cells(&glider);
+        (0..100).for_each(|_| hashlife.step(1).unwrap());
+        let (nodes, cells) = (hashlife.nodes.len(), hashlife.cells());
+
+        hashlife.collect_garbage();
+        assert!(hashlife.nodes.len() < nodes);
+        assert!(hashlife.results.is_empty());
+        assert_eq!(hashlife.cells(), cells);
+        assert_eq!(hashlife.generation(), 100);
+
+        hashlife.step(4).unwrap();
+        let moved = Cells::from_iter(cells.iter().map(|c| *c + Position::new(1, 1)));
+        assert_eq!(hashlife.cells(), moved);
+    }
+}
@@ -0,0 +1,192 @@
+use std::str::FromStr;
+
+use super::{position::Position, rule::RuleError};
+
+const MAX_RANGE: usize = 500;
+
+/// The cells within range of a cell that count as its neighbours.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NeighbourhoodShape {
+    /// The square of cells within the range in both directions.
+    #[default]
+    Moore,
+    /// The diamond of cells within the range in taxicab distance.
+    VonNeumann,
+    /// The cells whose centres lie within half a cell beyond the range.
+    Circular,
+}
+
+impl NeighbourhoodShape {
+    fn contains(&self, range: usize, offset: &Position) -> bool {
+        let (r, c) = (offset.row().unsigned_abs(), offset.column().unsigned_abs());
+        match self {
+            NeighbourhoodShape::Moore => r <= range && c <= range,
+            NeighbourhoodShape::VonNeumann => r + c <= range,
+            NeighbourhoodShape::Circular => r * r + c * c <= range * range + range,
+        }
+    }
+
+    fn tag(&self) -> char {
+        match self {
+            NeighbourhoodShape::Moore => 'M',
+            NeighbourhoodShape::VonNeumann => 'N',
+            NeighbourhoodShape::Circular => 'C',
+        }
+    }
+}
+
+/// A [Larger than Life](https://conwaylife.com/wiki/Larger_than_Life) rule, counting the live
+/// cells within a range of up to 500 cells, such as `R5,C0,M1,S34..58,B34..45,NM`.
+///
+/// The counts for survival and birth are inclusive ranges. When the middle cell is included, a
+/// live cell counts itself towards survival.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LargerThanLife {
+    range: usize,
+    states: u8,
+    is_middle_included: bool,
+    survival: (usize, usize),
+    birth: (usize, usize),
+    shape: NeighbourhoodShape,
+}
+
+impl LargerThanLife {
+    pub fn range(&self) -> usize {
+        self.range
+    }
+
+    pub fn states(&self) -> u8 {
+        self.states
+    }
+
+    pub fn is_middle_included(&self) -> bool {
+        self.is_middle_included
+    }
+
+    pub fn shape(&self) -> NeighbourhoodShape {
+        self.shape
+    }
+
+    pub fn is_born(&self, count: usize) -> bool {
+        (self.birth.0..=self.birth.1).contains(&count)
+    }
+
+    pub fn survives(&self, count: usize) -> bool {
+        (self.survival.0..=self.survival.1).contains(&count)
+    }
+
+    /// The offsets of every neighbour, excluding the middle cell.
+    pub fn offsets(&self) -> Vec<Position> {
+        let range = self.range as isize;
+        (-range..=range)
+            .flat_map(|r| (-range..=range).map(move |c| Position::new(r, c)))
+            .filter(|p| *p != Position::new(0, 0) && self.shape.contains(self.range, p))
+            .collect()
+    }
+}
+
+fn parse_counts(s: &str) -> Option<(usize, usize)> {
+    let (min, max) = s.split_once("..")?;
+    let (min, max) = (min.parse().ok()?, max.parse().ok()?);
+    (min <= max).then_some((min, max))
+}
+
+impl FromStr for LargerThanLife {
+    type Err = RuleError;
+
+    /// Accepts Golly's notation, with `C` (states), `M` (middle) and `N` (neighbourhood) being
+    /// optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RuleError::InvalidRule(s.into());
+
+        let (mut range, mut survival, mut birth) = (None, None, None);
+        let mut rule = LargerThanLife {
+            range: 1,
+            states: 2,
+            is_middle_included: false,
+            survival: (0, 0),
+            birth: (0, 0),
+            shape: NeighbourhoodShape::default(),
+        };
+
+        for item in s.trim().split(',') {
+            let mut chars = item.chars();
+            let tag = chars.next().map(|c| c.to_ascii_uppercase());
+            let value = chars.as_str();
+            match tag {
+                Some('R') => range = value.parse::<usize>().ok(),
+                Some('C') => {
+                    rule.states = value.parse::<u8>().map_err(|_| invalid())?.max(2);
+                }
+                Some('M') => {
+                    rule.is_middle_included = match value {
+                        "0" => false,
+                        "1" => true,
+                        _ => return Err(invalid()),
+                    }
+                }
+                Some('S') => survival = parse_counts(value),
+                Some('B') => birth = parse_counts(value),
+                Some('N') => {
+                    rule.shape = match value.to_ascii_uppercase().as_str() {
+                        "M" => NeighbourhoodShape::Moore,
+                        "N" => NeighbourhoodShape::VonNeumann,
+                        "C" => NeighbourhoodShape::Circular,
+                        _ => return Err(invalid()),
+                    }
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        let (Some(range), Some(survival), Some(birth)) = (range, survival, birth) else {
+            return Err(invalid());
+        };
+        if !(1..=MAX_RANGE).contains(&range) || birth.0 == 0 {
+            return Err(invalid());
+        }
+
+        Ok(LargerThanLife {
+            range,
+            survival,
+            birth,
+            ..rule
+        })
+    }
+}
+
+impl std::fmt::Display for LargerThanLife {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let states = if self.states > 2 { self.states } else { 0 };
+        write!(
+            f,
+            "R{},C{},M{},S{}..{},B{}..{},N{}",
+            self.range,
+            states,
+            u8::from(self.is_middle_included),
+            self.survival.0,
+            self.survival.1,
+            self.birth.0,
+            self.birth.1,
+            self.shape.tag()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn rule(shape: &str) -> LargerThanLife {
+        format!("R2,S1..1,B1..1,N{}", shape).parse().unwrap()
+    }
+
+    #[test]
+    fn neighbourhoods_have_the_expected_size() {
+        assert_eq!(rule("M").offsets().len(), 24);
+        assert_eq!(rule("N").offsets().len(), 12);
+        assert_eq!(rule("C").offsets().len(), 20);
+    }
+}
@@ -1,15 +1,49 @@
+mod algorithm;
+mod apgcode;
 mod bounds;
 mod cell;
 mod cells;
+mod census;
+mod classification;
+mod connectivity;
+mod cycle;
+mod engine;
+mod format;
 mod generations;
+mod hashlife;
+mod larger_than_life;
+mod neighbourhood;
+mod orientation;
 mod pattern;
 mod position;
+mod rule;
+mod search;
+mod states;
+mod tiles;
+mod topology;
 mod world;
 
+pub use algorithm::{Algorithm, AlgorithmError};
+pub use apgcode::{Apgcode, ApgcodeError};
 pub use bounds::Bounds;
 pub use cell::Cell;
 pub use cells::{Cells, CellsError};
-pub use generations::Generations;
+pub use census::{Census, CensusError};
+pub use classification::Classification;
+pub use connectivity::Connectivity;
+pub use cycle::Cycle;
+pub use engine::Engine;
+pub use format::{FormatError, Life105, Life106, Macrocell, Metadata, Plaintext, Rle};
+pub use generations::{Generations, History};
+pub use hashlife::{HashLife, HashLifeError};
+pub use larger_than_life::{LargerThanLife, NeighbourhoodShape};
+pub use neighbourhood::Neighbourhood;
+pub use orientation::Orientation;
 pub use pattern::Pattern;
 pub use position::Position;
+pub use rule::{Rule, RuleError};
+pub use search::Search;
+pub use states::CellStates;
+pub use tiles::{Tiles, TilesError};
+pub use topology::{Edges, Topology, TopologyError};
 pub use world::{World, WorldError};
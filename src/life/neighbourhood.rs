@@ -0,0 +1,150 @@
+use std::sync::LazyLock;
+
+use super::cell::Cell;
+
+/// The [Hensel](https://conwaylife.com/wiki/Isotropic_non-totalistic_rule) letters naming the
+/// isotropic classes of each neighbour count, in their conventional order.
+pub(crate) const LETTERS: [&str; 9] = [
+    "",
+    "ce",
+    "ceaikn",
+    "ceaiknjqry",
+    "ceaiknjqrytwz",
+    "ceaiknjqry",
+    "ceaikn",
+    "ce",
+    "",
+];
+
+/// A representative configuration of each class of up to four neighbours, in the order of
+/// [LETTERS]. Classes of more than four neighbours are the complements of those with fewer.
+const REPRESENTATIVES: [&[u8]; 5] = [
+    &[0],
+    &[1, 2],
+    &[5, 10, 3, 24, 17, 36],
+    &[37, 26, 11, 7, 50, 13, 14, 38, 25, 49],
+    &[165, 90, 15, 29, 51, 39, 58, 54, 27, 53, 57, 46, 60],
+];
+
+/// A rotation or reflection of the square, applied to a cell's (row, column) offset.
+type Transform = fn(isize, isize) -> (isize, isize);
+
+/// The letter of every configuration, found by applying the eight symmetries of the square to
+/// each representative.
+static CLASSES: LazyLock<[Option<char>; 256]> = LazyLock::new(|| {
+    let mut classes = [None; 256];
+    for (count, representatives) in REPRESENTATIVES.iter().enumerate() {
+        for (letter, representative) in LETTERS[count].chars().zip(representatives.iter()) {
+            for neighbourhood in Neighbourhood(*representative).symmetries() {
+                classes[neighbourhood.0 as usize] = Some(letter);
+                if count < 4 {
+                    classes[neighbourhood.complement().0 as usize] = Some(letter);
+                }
+            }
+        }
+    }
+    classes
+});
+
+/// The live neighbours of a cell, as one bit per neighbour in the order of [Cell::neighbours].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Neighbourhood(u8);
+
+impl Neighbourhood {
+    pub fn new(mask: u8) -> Self {
+        Self(mask)
+    }
+
+    /// The neighbourhood of `cell`, given which cells are live.
+    pub fn of(cell: &Cell, is_live: impl Fn(&Cell) -> bool) -> Self {
+        Self(
+            cell.neighbours()
+                .enumerate()
+                .filter(|(_, c)| is_live(c))
+                .fold(0, |mask, (i, _)| mask | 1 << i),
+        )
+    }
+
+    /// Every possible neighbourhood.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..=u8::MAX).map(Self)
+    }
+
+    pub fn mask(&self) -> u8 {
+        self.0
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// The Hensel letter of the isotropic class, if the count has more than one class.
+    pub fn letter(&self) -> Option<char> {
+        CLASSES[self.0 as usize]
+    }
+
+    pub fn complement(&self) -> Self {
+        Self(!self.0)
+    }
+
+    /// The neighbourhood under each rotation and reflection of the square.
+    pub fn symmetries(&self) -> impl Iterator<Item = Self> + '_ {
+        let offsets = Cell::new(0, 0).neighbours().collect::<Vec<_>>();
+        let index = move |cell: Cell| offsets.iter().position(|o| *o == cell).unwrap();
+        let transforms: [Transform; 8] = [
+            |r, c| (r, c),
+            |r, c| (c, -r),
+            |r, c| (-r, -c),
+            |r, c| (-c, r),
+            |r, c| (r, -c),
+            |r, c| (-r, c),
+            |r, c| (c, r),
+            |r, c| (-c, -r),
+        ];
+
+        transforms.into_iter().map(move |transform| {
+            Self(
+                Cell::new(0, 0)
+                    .neighbours()
+                    .enumerate()
+                    .filter(|(i, _)| self.0 & 1 << i != 0)
+                    .map(|(_, c)| transform(c.row(), c.column()))
+                    .fold(0, |mask, (r, c)| mask | 1 << index(Cell::new(r, c))),
+            )
+        })
+    }
+}
+
+impl std::fmt::Display for Neighbourhood {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.count())?;
+        if let Some(letter) = self.letter() {
+            write!(f, "{}", letter)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn classes_partition_every_neighbourhood() {
+        let classes = Neighbourhood::all()
+            .map(|n| n.to_string())
+            .collect::<HashSet<_>>();
+        let orbits = Neighbourhood::all()
+            .map(|n| n.symmetries().map(|s| s.mask()).min())
+            .collect::<HashSet<_>>();
+        assert_eq!(classes.len(), 51);
+        assert_eq!(orbits.len(), 51);
+        assert!(
+            Neighbourhood::all().all(|n| n.symmetries().all(|s| s.to_string() == n.to_string()))
+        );
+    }
+}
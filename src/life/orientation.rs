@@ -0,0 +1,73 @@
+use super::{bounds::Bounds, cell::Cell};
+
+/// One of the eight symmetries of a square, the dihedral group D8: the four rotations, clockwise,
+/// and the four reflections, in the vertical and horizontal axes and the two diagonals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Orientation {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipHorizontal,
+    FlipVertical,
+    Transpose,
+    AntiTranspose,
+}
+
+impl Orientation {
+    pub const ALL: [Orientation; 8] = [
+        Orientation::Identity,
+        Orientation::Rotate90,
+        Orientation::Rotate180,
+        Orientation::Rotate270,
+        Orientation::FlipHorizontal,
+        Orientation::FlipVertical,
+        Orientation::Transpose,
+        Orientation::AntiTranspose,
+    ];
+
+    /// The orientation that undoes this one.
+    pub fn inverse(&self) -> Self {
+        match self {
+            Orientation::Rotate90 => Orientation::Rotate270,
+            Orientation::Rotate270 => Orientation::Rotate90,
+            other => *other,
+        }
+    }
+
+    /// Where `cell` goes when the `bounds` it lies in are reoriented in place: the cells stay
+    /// within the bounds, or within the bounds with rows and columns exchanged when turned a
+    /// quarter or reflected in a diagonal.
+    pub(crate) fn apply(&self, cell: &Cell, bounds: &Bounds) -> Cell {
+        let (rows, columns) = (bounds.rows(), bounds.columns());
+        let (row, column) = (cell.row(), cell.column());
+        let flip_row = rows.start() + rows.end() - row;
+        let flip_column = columns.start() + columns.end() - column;
+        match self {
+            Orientation::Identity => *cell,
+            Orientation::Rotate90 => Cell::new(column, flip_row),
+            Orientation::Rotate180 => Cell::new(flip_row, flip_column),
+            Orientation::Rotate270 => Cell::new(flip_column, row),
+            Orientation::FlipHorizontal => Cell::new(row, flip_column),
+            Orientation::FlipVertical => Cell::new(flip_row, column),
+            Orientation::Transpose => Cell::new(column, row),
+            Orientation::AntiTranspose => Cell::new(flip_column, flip_row),
+        }
+    }
+}
+
+impl std::fmt::Display for Orientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Orientation::Identity => "identity",
+            Orientation::Rotate90 => "rotated 90°",
+            Orientation::Rotate180 => "rotated 180°",
+            Orientation::Rotate270 => "rotated 270°",
+            Orientation::FlipHorizontal => "flipped horizontally",
+            Orientation::FlipVertical => "flipped vertically",
+            Orientation::Transpose => "transposed",
+            Orientation::AntiTranspose => "anti-transposed",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -1,7 +1,7 @@
 /// Arbitary [Position] on a board.
 /// @param row
 /// @param column
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Position {
     row: isize,
     column: isize,
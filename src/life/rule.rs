@@ -0,0 +1,310 @@
+use std::str::FromStr;
+
+use thiserror::*;
+
+use super::{
+    cell::Cell,
+    larger_than_life::LargerThanLife,
+    neighbourhood::{LETTERS, Neighbourhood},
+    position::Position,
+};
+
+const MAX_NEIGHBOURS: usize = 8;
+const NEIGHBOURHOODS: usize = 256;
+
+/// A set of neighbourhoods, one bit per neighbourhood mask.
+type NeighbourhoodSet = [u64; NEIGHBOURHOODS / 64];
+
+fn contains(set: &NeighbourhoodSet, neighbourhood: Neighbourhood) -> bool {
+    let mask = neighbourhood.mask() as usize;
+    set[mask / 64] & 1 << (mask % 64) != 0
+}
+
+fn insert(set: &mut NeighbourhoodSet, neighbourhood: Neighbourhood) {
+    let mask = neighbourhood.mask() as usize;
+    set[mask / 64] |= 1 << (mask % 64);
+}
+
+/// Rules better known by name than by rulestring.
+const NAMED_RULES: [(&str, &str); 4] = [
+    ("life", "B3/S23"),
+    ("highlife", "B36/S23"),
+    ("seeds", "B2/S"),
+    ("tlife", "B3/S2-i34q"),
+];
+
+#[derive(Debug, Error)]
+pub enum RuleError {
+    #[error("invalid rule '{0}'")]
+    InvalidRule(String),
+}
+
+/// An [isotropic](https://conwaylife.com/wiki/Isotropic_non-totalistic_rule) rule, deciding the
+/// next state of a cell from its current state and the arrangement of its live neighbours, up to
+/// rotation and reflection. Outer-totalistic rules, which depend only on the number of live
+/// neighbours, are the special case where every arrangement of a count behaves alike.
+///
+/// Rules of the [Generations](https://conwaylife.com/wiki/Generations) family have more than two
+/// states: a live cell that does not survive passes through the decaying states `2..states`
+/// before dying, and decaying cells neither count as neighbours nor can be born.
+///
+/// [Larger than Life](LargerThanLife) rules instead count the live cells within a wider range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rule {
+    conditions: Conditions,
+    states: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Conditions {
+    Isotropic {
+        birth: NeighbourhoodSet,
+        survival: NeighbourhoodSet,
+    },
+    LargerThanLife(LargerThanLife),
+}
+
+impl Rule {
+    pub fn states(&self) -> u8 {
+        self.states
+    }
+
+    /// Whether a cell is born with `count` live neighbours, whatever their arrangement.
+    pub fn is_born(&self, count: usize) -> bool {
+        match &self.conditions {
+            Conditions::Isotropic { .. } => with_count(count).all(|n| self.is_born_with(n)),
+            Conditions::LargerThanLife(rule) => rule.is_born(count),
+        }
+    }
+
+    /// Whether a cell survives with `count` live neighbours, whatever their arrangement. The
+    /// count includes the cell itself when a Larger than Life rule includes the middle cell.
+    pub fn survives(&self, count: usize) -> bool {
+        match &self.conditions {
+            Conditions::Isotropic { .. } => with_count(count).all(|n| self.survives_with(n)),
+            Conditions::LargerThanLife(rule) => rule.survives(count),
+        }
+    }
+
+    pub fn is_born_with(&self, neighbourhood: Neighbourhood) -> bool {
+        match &self.conditions {
+            Conditions::Isotropic { birth, .. } => contains(birth, neighbourhood),
+            Conditions::LargerThanLife(rule) => rule.is_born(neighbourhood.count()),
+        }
+    }
+
+    pub fn survives_with(&self, neighbourhood: Neighbourhood) -> bool {
+        match &self.conditions {
+            Conditions::Isotropic { survival, .. } => contains(survival, neighbourhood),
+            Conditions::LargerThanLife(rule) => rule.survives(neighbourhood.count()),
+        }
+    }
+
+    /// Whether the rule depends only on the number of live cells among the eight neighbours.
+    pub fn is_totalistic(&self) -> bool {
+        let is_uniform = |conditions: &NeighbourhoodSet| {
+            Neighbourhood::all().all(|n| {
+                contains(conditions, n)
+                    == contains(
+                        conditions,
+                        Neighbourhood::new(((1u16 << n.count()) - 1) as u8),
+                    )
+            })
+        };
+        match &self.conditions {
+            Conditions::Isotropic { birth, survival } => is_uniform(birth) && is_uniform(survival),
+            Conditions::LargerThanLife(_) => false,
+        }
+    }
+
+    pub fn larger_than_life(&self) -> Option<&LargerThanLife> {
+        match &self.conditions {
+            Conditions::Isotropic { .. } => None,
+            Conditions::LargerThanLife(rule) => Some(rule),
+        }
+    }
+
+    /// The offsets of the cells counted as neighbours.
+    pub fn offsets(&self) -> Vec<Position> {
+        match &self.conditions {
+            Conditions::Isotropic { .. } => Cell::new(0, 0)
+                .neighbours()
+                .map(|c| Position::new(c.row(), c.column()))
+                .collect(),
+            Conditions::LargerThanLife(rule) => rule.offsets(),
+        }
+    }
+}
+
+impl Default for Rule {
+    /// Conway's B3/S23.
+    fn default() -> Self {
+        "B3/S23".parse().unwrap()
+    }
+}
+
+fn with_count(count: usize) -> impl Iterator<Item = Neighbourhood> {
+    Neighbourhood::all().filter(move |n| n.count() == count)
+}
+
+/// Parses counts such as `23`, optionally qualified by Hensel letters that include (`2ak`) or,
+/// after a minus, exclude (`2-ak`) arrangements of that count.
+fn parse_conditions(s: &str, conditions: &mut NeighbourhoodSet) -> Option<()> {
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let count = c
+            .to_digit(10)
+            .map(|n| n as usize)
+            .filter(|n| *n <= MAX_NEIGHBOURS)?;
+        let is_excluded = chars.next_if_eq(&'-').is_some();
+        let mut letters = Vec::new();
+        while let Some(letter) = chars.next_if(char::is_ascii_lowercase) {
+            letters.push(letter);
+        }
+
+        if letters.iter().any(|l| !LETTERS[count].contains(*l))
+            || (is_excluded && letters.is_empty())
+        {
+            return None;
+        }
+
+        with_count(count)
+            .filter(|n| {
+                letters.is_empty()
+                    || n.letter().is_some_and(|l| letters.contains(&l)) != is_excluded
+            })
+            .for_each(|n| insert(conditions, n));
+    }
+    Some(())
+}
+
+impl FromStr for Rule {
+    type Err = RuleError;
+
+    /// Accepts `B36/S23` and `b36s23` notations, in either order, and the older `23/36`
+    /// survival/birth notation. Generations rules add a state count, as in `B2/S/C3` or
+    /// `345/2/4`. Counts may be qualified with Hensel letters, as in `B2-a/S12`, and a few rules
+    /// may be given by name, such as `tlife`. Larger than Life rules start with their range, as in
+    /// `R5,C0,M1,S34..58,B34..45,NM`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RuleError::InvalidRule(s.into());
+
+        let mut birth = NeighbourhoodSet::default();
+        let mut survival = NeighbourhoodSet::default();
+
+        let rule = s.trim().to_ascii_lowercase();
+        if let Some((_, rule)) = NAMED_RULES.iter().find(|(name, _)| *name == rule) {
+            return rule.parse();
+        }
+        if rule.starts_with('r') {
+            let rule = s.parse::<LargerThanLife>()?;
+            return Ok(Self {
+                conditions: Conditions::LargerThanLife(rule),
+                states: rule.states(),
+            });
+        }
+
+        let states = if rule.contains(['b', 's']) {
+            let mut sections = (None, None, None);
+            let mut remainder = rule.as_str();
+            while let Some(tag) = remainder.chars().next() {
+                let section = match tag {
+                    'b' => &mut sections.0,
+                    's' => &mut sections.1,
+                    'c' | 'g' => &mut sections.2,
+                    _ => return Err(invalid()),
+                };
+                // Within a section, `c` is a Hensel letter rather than the state count tag.
+                let rest = &remainder[1..];
+                let (conditions, rest) =
+                    rest.split_at(rest.find(['/', 'b', 's', 'g']).unwrap_or(rest.len()));
+                if section.replace(conditions).is_some() {
+                    return Err(invalid());
+                }
+                remainder = rest.strip_prefix('/').unwrap_or(rest);
+            }
+
+            let (Some(b), Some(s), c) = sections else {
+                return Err(invalid());
+            };
+            parse_conditions(b, &mut birth).ok_or_else(invalid)?;
+            parse_conditions(s, &mut survival).ok_or_else(invalid)?;
+            c
+        } else {
+            let sections = rule.split('/').collect::<Vec<_>>();
+            let (s, b, c) = match sections[..] {
+                [s, b] => (s, b, None),
+                [s, b, c] => (s, b, Some(c)),
+                _ => return Err(invalid()),
+            };
+            parse_conditions(s, &mut survival).ok_or_else(invalid)?;
+            parse_conditions(b, &mut birth).ok_or_else(invalid)?;
+            c
+        };
+
+        let states = match states {
+            Some(c) => c
+                .parse::<u8>()
+                .ok()
+                .filter(|c| *c >= 2)
+                .ok_or_else(invalid)?,
+            None => 2,
+        };
+        if states > 2 && contains(&birth, Neighbourhood::new(0)) {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            conditions: Conditions::Isotropic { birth, survival },
+            states,
+        })
+    }
+}
+
+/// Writes the counts met by `is_met`, each qualified by whichever of its included or excluded
+/// Hensel letters is the shorter list.
+fn conditions(is_met: impl Fn(Neighbourhood) -> bool) -> String {
+    let mut conditions = String::new();
+    for (count, letters) in LETTERS.iter().enumerate() {
+        let representative = |letter| with_count(count).find(|n| n.letter() == Some(letter));
+        let (included, excluded): (String, String) = letters
+            .chars()
+            .partition(|l| representative(*l).is_some_and(&is_met));
+
+        if letters.is_empty() {
+            if with_count(count).all(&is_met) {
+                conditions.push_str(&count.to_string());
+            }
+        } else if excluded.is_empty() {
+            conditions.push_str(&count.to_string());
+        } else if !included.is_empty() {
+            conditions.push_str(&count.to_string());
+            if included.len() <= excluded.len() {
+                conditions.push_str(&included);
+            } else {
+                conditions.push('-');
+                conditions.push_str(&excluded);
+            }
+        }
+    }
+    conditions
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(rule) = self.larger_than_life() {
+            return write!(f, "{}", rule);
+        }
+
+        write!(
+            f,
+            "B{}/S{}",
+            conditions(|n| self.is_born_with(n)),
+            conditions(|n| self.survives_with(n))
+        )?;
+        if self.states > 2 {
+            write!(f, "/C{}", self.states)?;
+        }
+        Ok(())
+    }
+}
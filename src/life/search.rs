@@ -0,0 +1,107 @@
+use rayon::prelude::*;
+
+use super::{cell::Cell, cells::Cells, orientation::Orientation, position::Position};
+
+/// A pattern to look for among cells, in each of its orientations. The pattern matches where its
+/// cells are live and the rest of its bounds are dead; with a dead border, the cells around its
+/// bounds must be dead too, so that it is not just part of something larger.
+#[derive(Clone, Debug)]
+pub struct Search {
+    shapes: Vec<Shape>,
+    dead_border: bool,
+}
+
+/// The pattern in one orientation, moved to the origin.
+#[derive(Clone, Debug)]
+struct Shape {
+    orientation: Orientation,
+    live: Vec<Cell>,
+    dead: Vec<Cell>,
+    border: Vec<Cell>,
+}
+
+impl Search {
+    /// A search for `pattern`, in each orientation that differs from those before it.
+    pub fn new(pattern: &Cells) -> Self {
+        let mut orientations: Vec<(Orientation, Cells)> = Vec::new();
+        for orientation in Orientation::ALL {
+            let cells = pattern.clone().transform(&orientation).normalise();
+            if orientations.iter().all(|(_, seen)| *seen != cells) {
+                orientations.push((orientation, cells));
+            }
+        }
+
+        let shapes = orientations
+            .into_iter()
+            .filter(|(_, cells)| !cells.is_empty())
+            .map(|(orientation, cells)| Shape::new(orientation, &cells))
+            .collect();
+        Self {
+            shapes,
+            dead_border: false,
+        }
+    }
+
+    /// Requires the cells around the pattern's bounds to be dead.
+    pub fn with_dead_border(&mut self, dead_border: bool) {
+        self.dead_border = dead_border;
+    }
+
+    pub fn dead_border(&self) -> bool {
+        self.dead_border
+    }
+
+    /// The top left of the pattern's bounds wherever it is found in `cells`, and the orientation
+    /// it was found in, in order of position.
+    pub(crate) fn occurrences(&self, cells: &Cells) -> Vec<(Position, Orientation)> {
+        let mut occurrences = self
+            .shapes
+            .par_iter()
+            .flat_map_iter(|shape| {
+                let anchor = shape.live[0];
+                cells.iter().filter_map(move |cell| {
+                    let position =
+                        Position::new(cell.row() - anchor.row(), cell.column() - anchor.column());
+                    self.is_match(shape, cells, &position)
+                        .then_some((position, shape.orientation))
+                })
+            })
+            .collect::<Vec<_>>();
+        occurrences.sort_by_key(|(position, orientation)| {
+            (position.row(), position.column(), *orientation)
+        });
+        occurrences
+    }
+
+    fn is_match(&self, shape: &Shape, cells: &Cells, position: &Position) -> bool {
+        let is_live = |cell: &Cell| cells.contains(&(*cell + *position));
+        shape.live.iter().all(is_live)
+            && !shape.dead.iter().any(is_live)
+            && !(self.dead_border && shape.border.iter().any(is_live))
+    }
+}
+
+impl Shape {
+    fn new(orientation: Orientation, cells: &Cells) -> Self {
+        let bounds = cells.bounds();
+        let (height, width) = (bounds.height() as isize, bounds.width() as isize);
+
+        let mut live = Vec::from_iter(cells.iter().copied());
+        live.sort();
+        let dead = (0..height)
+            .flat_map(|r| (0..width).map(move |c| Cell::new(r, c)))
+            .filter(|cell| !cells.contains(cell))
+            .collect();
+        let border = (-1..=height)
+            .flat_map(|r| (-1..=width).map(move |c| Cell::new(r, c)))
+            .filter(|cell| !bounds.contains(cell))
+            .collect();
+
+        Self {
+            orientation,
+            live,
+            dead,
+            border,
+        }
+    }
+}
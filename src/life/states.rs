@@ -0,0 +1,45 @@
+use std::ops::{Deref, DerefMut};
+
+use hashable::HashableHashMap;
+
+use super::{bounds::Bounds, cell::Cell};
+
+/// The cells in the decaying states of a multi-state rule, keyed by cell. Live cells (state 1)
+/// are held separately as [Cells](super::cells::Cells); absent cells are dead (state 0).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct CellStates(HashableHashMap<Cell, u8>);
+
+impl CellStates {
+    pub fn state(&self, cell: &Cell) -> u8 {
+        self.0.get(cell).copied().unwrap_or_default()
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        let mut bounds = Bounds::default();
+        self.0.keys().for_each(|cell| {
+            bounds.encompass(cell);
+        });
+        bounds
+    }
+}
+
+impl Deref for CellStates {
+    type Target = HashableHashMap<Cell, u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CellStates {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl FromIterator<(Cell, u8)> for CellStates {
+    fn from_iter<T: IntoIterator<Item = (Cell, u8)>>(iter: T) -> Self {
+        Self(HashableHashMap::from_iter(iter))
+    }
+}
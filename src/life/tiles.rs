@@ -0,0 +1,385 @@
+use std::collections::{HashMap, HashSet};
+
+use thiserror::*;
+
+use super::{
+    bounds::Bounds,
+    cell::Cell,
+    cells::Cells,
+    engine::{Engine, normalised},
+    position::Position,
+    rule::Rule,
+};
+
+/// The width and height of a tile, one cell per bit of a word.
+const SIZE: isize = u64::BITS as isize;
+
+#[derive(Debug, Error)]
+pub enum TilesError {
+    #[error("rule '{0}' is not supported by tiles")]
+    UnsupportedRule(Rule),
+}
+
+/// The rows of a tile, bit `c` of row `r` being the cell at column `c`.
+type Tile = [u64; SIZE as usize];
+
+/// The row and column of a tile, in tiles.
+type Key = (isize, isize);
+
+const EMPTY: Tile = [0; SIZE as usize];
+
+/// A universe of 64 by 64 tiles of bit-packed cells, whose neighbours are counted a whole row at
+/// a time. Only the tiles that changed in the last generation, and their neighbours, are
+/// recomputed, so dense soups that have largely settled are cheap to advance.
+///
+/// Only two-state totalistic rules of the eight cell neighbourhood without B0 are supported, and
+/// the universe is unbounded.
+#[derive(Clone, Debug)]
+pub struct Tiles {
+    rule: Rule,
+    births: Vec<usize>,
+    survivals: Vec<usize>,
+    tiles: HashMap<Key, Tile>,
+    active: HashSet<Key>,
+    generation: u64,
+}
+
+impl Tiles {
+    pub fn new(rule: &Rule) -> Result<Self, TilesError> {
+        if !Self::supports(rule) {
+            return Err(TilesError::UnsupportedRule(*rule));
+        }
+
+        Ok(Self {
+            rule: *rule,
+            births: (0..=8).filter(|n| rule.is_born(*n)).collect(),
+            survivals: (0..=8).filter(|n| rule.survives(*n)).collect(),
+            tiles: HashMap::new(),
+            active: HashSet::new(),
+            generation: 0,
+        })
+    }
+
+    pub fn supports(rule: &Rule) -> bool {
+        rule.states() == 2 && rule.is_totalistic() && !rule.is_born(0)
+    }
+
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// The number of generations stepped since the cells were last set.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn population(&self) -> u64 {
+        self.tiles
+            .values()
+            .flatten()
+            .map(|row| u64::from(row.count_ones()))
+            .sum()
+    }
+
+    /// The number of tiles holding live cells.
+    pub fn tile_count(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// Replaces the universe's cells, marking every tile as active.
+    pub fn with_cells(&mut self, cells: &Cells) {
+        self.tiles.clear();
+        for cell in cells.iter() {
+            let key = (cell.row().div_euclid(SIZE), cell.column().div_euclid(SIZE));
+            let tile = self.tiles.entry(key).or_insert(EMPTY);
+            tile[cell.row().rem_euclid(SIZE) as usize] |= 1 << cell.column().rem_euclid(SIZE);
+        }
+        self.active = self.tiles.keys().copied().collect();
+        self.generation = 0;
+    }
+
+    pub fn cells(&self) -> Cells {
+        self.cells_where(|_| true)
+    }
+
+    /// The live cells within `rect`, skipping the tiles lying wholly outside it.
+    pub fn cells_in(&self, rect: &Bounds) -> Cells {
+        if !rect.is_defined() {
+            return Cells::default();
+        }
+
+        let (rows, columns) = (rect.rows(), rect.columns());
+        let tile_rows = rows.start().div_euclid(SIZE)..=rows.end().div_euclid(SIZE);
+        let tile_columns = columns.start().div_euclid(SIZE)..=columns.end().div_euclid(SIZE);
+        let cells = self.cells_where(|(r, c)| tile_rows.contains(r) && tile_columns.contains(c));
+        Cells::from_iter(cells.iter().filter(|c| rect.contains(c)).copied())
+    }
+
+    /// Sets the state of `cell`, marking its tile as active.
+    pub fn with_cell(&mut self, cell: &Cell, is_live: bool) {
+        let key = (cell.row().div_euclid(SIZE), cell.column().div_euclid(SIZE));
+        let tile = self.tiles.entry(key).or_insert(EMPTY);
+        let bit = 1 << cell.column().rem_euclid(SIZE);
+        let row = &mut tile[cell.row().rem_euclid(SIZE) as usize];
+        if is_live {
+            *row |= bit;
+        } else {
+            *row &= !bit;
+        }
+        if *tile == EMPTY {
+            self.tiles.remove(&key);
+        }
+        self.active.insert(key);
+    }
+
+    /// Kills the cells outside `bounds`, marking the tiles that change as active; tiles lying
+    /// wholly within them are left alone.
+    pub fn clip(&mut self, bounds: &Bounds) {
+        if !bounds.is_defined() {
+            return;
+        }
+
+        let (rows, columns) = (bounds.rows(), bounds.columns());
+        let is_within = |range: &std::ops::RangeInclusive<isize>, start: isize| {
+            range.contains(&start) && range.contains(&(start + SIZE - 1))
+        };
+        let mut changed = Vec::new();
+        for (&(tile_row, tile_column), tile) in self.tiles.iter_mut() {
+            let (top, left) = (tile_row * SIZE, tile_column * SIZE);
+            if is_within(rows, top) && is_within(columns, left) {
+                continue;
+            }
+
+            let mask = (0..SIZE)
+                .filter(|c| columns.contains(&(left + c)))
+                .fold(0, |mask, c| mask | 1 << c);
+            let before = *tile;
+            for (r, row) in tile.iter_mut().enumerate() {
+                *row &= if rows.contains(&(top + r as isize)) {
+                    mask
+                } else {
+                    0
+                };
+            }
+            if *tile != before {
+                changed.push((tile_row, tile_column));
+            }
+        }
+
+        for key in changed {
+            if self.tiles.get(&key) == Some(&EMPTY) {
+                self.tiles.remove(&key);
+            }
+            self.active.insert(key);
+        }
+    }
+
+    fn cells_where(&self, is_wanted: impl Fn(&Key) -> bool) -> Cells {
+        let mut cells = Vec::new();
+        let tiles = self.tiles.iter().filter(|(key, _)| is_wanted(key));
+        for ((tile_row, tile_column), tile) in tiles {
+            for (r, row) in tile.iter().enumerate() {
+                let mut bits = *row;
+                while bits != 0 {
+                    let c = bits.trailing_zeros() as isize;
+                    cells.push(Cell::new(
+                        tile_row * SIZE + r as isize,
+                        tile_column * SIZE + c,
+                    ));
+                    bits &= bits - 1;
+                }
+            }
+        }
+        Cells::from_iter(cells)
+    }
+
+    /// Advances the universe by `generations`, one at a time.
+    pub fn step(&mut self, generations: u64) {
+        for _ in 0..generations {
+            self.next_generation();
+        }
+        self.generation += generations;
+    }
+
+    /// Recomputes the active tiles and their neighbours; the rest cannot have changed, as nothing
+    /// around them did.
+    fn next_generation(&mut self) {
+        let candidates = self
+            .active
+            .iter()
+            .flat_map(|(r, c)| {
+                (-1..=1).flat_map(move |dr| (-1..=1).map(move |dc| (r + dr, c + dc)))
+            })
+            .collect::<HashSet<_>>();
+
+        let updates = candidates
+            .into_iter()
+            .map(|key| (key, self.next_tile(key)))
+            .collect::<Vec<_>>();
+
+        self.active.clear();
+        for (key, next) in updates {
+            let current = self.tiles.get(&key).unwrap_or(&EMPTY);
+            if *current == next {
+                continue;
+            }
+            self.active.insert(key);
+            if next == EMPTY {
+                self.tiles.remove(&key);
+            } else {
+                self.tiles.insert(key, next);
+            }
+        }
+    }
+
+    /// The tile at `key`, one generation on.
+    fn next_tile(&self, (row, column): Key) -> Tile {
+        let around: [[&Tile; 3]; 3] = std::array::from_fn(|dr| {
+            std::array::from_fn(|dc| {
+                let key = (row + dr as isize - 1, column + dc as isize - 1);
+                self.tiles.get(&key).unwrap_or(&EMPTY)
+            })
+        });
+
+        // The row `r` of the tile, which may be one beyond either edge, with the rows of its
+        // west and east neighbours aligned to each cell.
+        let aligned = |r: isize| {
+            let (tiles, r) = match r {
+                -1 => (&around[0], SIZE - 1),
+                SIZE => (&around[2], 0),
+                r => (&around[1], r),
+            };
+            let [west, centre, east] = tiles.map(|t| t[r as usize]);
+            (
+                centre << 1 | west >> (SIZE - 1),
+                centre,
+                centre >> 1 | east << (SIZE - 1),
+            )
+        };
+
+        let mut next = EMPTY;
+        for (r, row) in next.iter_mut().enumerate() {
+            let r = r as isize;
+            let (nw, n, ne) = aligned(r - 1);
+            let (w, alive, e) = aligned(r);
+            let (sw, s, se) = aligned(r + 1);
+
+            let mut counts = [0u64; 4];
+            for neighbours in [nw, n, ne, w, e, sw, s, se] {
+                let mut carry = neighbours;
+                for plane in counts.iter_mut() {
+                    let overflow = *plane & carry;
+                    *plane ^= carry;
+                    carry = overflow;
+                }
+            }
+            let with_count = |count: &usize| {
+                counts
+                    .iter()
+                    .enumerate()
+                    .fold(u64::MAX, |acc, (bit, plane)| {
+                        acc & if count & 1 << bit != 0 {
+                            *plane
+                        } else {
+                            !plane
+                        }
+                    })
+            };
+            let any_of = |counts: &[usize]| counts.iter().fold(0, |acc, n| acc | with_count(n));
+
+            *row = (alive & any_of(&self.survivals)) | (!alive & any_of(&self.births));
+        }
+        next
+    }
+}
+
+impl Engine for Tiles {
+    type Snapshot = Cells;
+
+    fn step(&mut self) {
+        Tiles::step(self, 1);
+    }
+
+    fn step_n(&mut self, generations: u64) {
+        Tiles::step(self, generations);
+    }
+
+    fn population(&self) -> usize {
+        Tiles::population(self) as usize
+    }
+
+    fn bounding_box(&self) -> Bounds {
+        self.cells().bounds()
+    }
+
+    fn cells_in(&self, rect: &Bounds) -> Cells {
+        Tiles::cells_in(self, rect)
+    }
+
+    fn set_cell(&mut self, cell: &Cell) {
+        self.with_cell(cell, true);
+    }
+
+    fn clear_cell(&mut self, cell: &Cell) {
+        self.with_cell(cell, false);
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.cells()
+    }
+
+    fn normalised_snapshot(&self) -> Option<(Self::Snapshot, Position)> {
+        Some(normalised(&self.cells()))
+    }
+}
+
+impl From<&Tiles> for Cells {
+    fn from(value: &Tiles) -> Self {
+        value.cells()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn cells_round_trip() {
+        let cells = Cells::from_iter([Cell::new(-3, 5), Cell::new(0, 0), Cell::new(70, -64)]);
+        let mut tiles = Tiles::new(&Rule::default()).unwrap();
+        tiles.with_cells(&cells);
+        assert_eq!(tiles.cells(), cells);
+        assert_eq!(tiles.population(), 3);
+        assert_eq!(tiles.tile_count(), 3);
+    }
+
+    #[test]
+    fn settled_tiles_are_inactive() {
+        let block =
+            Cells::from_iter([(0, 0), (0, 1), (1, 0), (1, 1)].map(|(r, c)| Cell::new(r, c)));
+        let mut tiles = Tiles::new(&Rule::default()).unwrap();
+        tiles.with_cells(&block);
+        tiles.step(1);
+        assert!(tiles.active.is_empty());
+    }
+
+    #[test]
+    fn clipped_cells_are_killed() {
+        let cells =
+            Cells::from_iter([(0, 0), (0, 70), (5, 5), (80, 5)].map(|(r, c)| Cell::new(r, c)));
+        let mut tiles = Tiles::new(&Rule::default()).unwrap();
+        tiles.with_cells(&cells);
+        tiles.step(1);
+        tiles.with_cells(&cells);
+        tiles.active.clear();
+        tiles.clip(&Bounds::new(0..=10, 0..=10));
+        assert_eq!(
+            tiles.cells(),
+            Cells::from_iter([Cell::new(0, 0), Cell::new(5, 5)])
+        );
+        assert_eq!(tiles.tile_count(), 1);
+        assert_eq!(tiles.active, HashSet::from([(0, 1), (1, 0)]));
+    }
+}
@@ -0,0 +1,229 @@
+use std::str::FromStr;
+
+use thiserror::*;
+
+use super::{bounds::Bounds, cell::Cell};
+
+#[derive(Debug, Error)]
+pub enum TopologyError {
+    #[error("invalid topology '{0}'")]
+    InvalidTopology(String),
+}
+
+/// A pair of opposite edges of a bounded world.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Edges {
+    /// The top and bottom edges.
+    #[default]
+    Horizontal,
+    /// The left and right edges.
+    Vertical,
+}
+
+/// How the edges of a bounded world are joined, following Golly's
+/// [bounded grids](https://golly.sourceforge.io/Help/bounded.html). Unbounded worlds have no
+/// edges, so their topology is ignored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Topology {
+    /// Cells beyond the edges are dead, and cells leaving the bounds are lost.
+    #[default]
+    Plane,
+    /// Opposite edges are joined, so cells leaving one edge reappear at the other.
+    Torus,
+    /// Opposite edges are joined, but the `twisted` pair are reversed first and then shifted
+    /// along by `shift` cells.
+    KleinBottle { twisted: Edges, shift: isize },
+    /// Both pairs of opposite edges are reversed before being joined.
+    CrossSurface,
+    /// The top edge is joined to the left edge, and the bottom edge to the right edge. Only
+    /// square bounds can be joined this way; the diagonal neighbours beyond a corner are dead.
+    Sphere,
+}
+
+impl Topology {
+    /// Whether the edges of `bounds` can be joined in this way.
+    pub fn is_valid_for(&self, bounds: &Bounds) -> bool {
+        match self {
+            Topology::Sphere => bounds.width() == bounds.height(),
+            _ => true,
+        }
+    }
+
+    /// The cell within `bounds` that `cell` stands for, once the edges are joined. Cells of a
+    /// plane are their own, even when beyond the edges, as nothing lives there.
+    pub fn wrap(&self, bounds: &Bounds, cell: &Cell) -> Option<Cell> {
+        if !bounds.is_defined() {
+            return Some(*cell);
+        }
+
+        let (top, left) = (*bounds.rows().start(), *bounds.columns().start());
+        let (height, width) = (bounds.height() as isize, bounds.width() as isize);
+        let (row, column) = (cell.row() - top, cell.column() - left);
+        let (crossings_down, crossings_across) = (row.div_euclid(height), column.div_euclid(width));
+        let is_odd = |crossings: isize| crossings.rem_euclid(2) == 1;
+
+        let (row, column) = match self {
+            Topology::Plane => return Some(*cell),
+            Topology::Torus => (row, column),
+            Topology::KleinBottle {
+                twisted: Edges::Horizontal,
+                shift,
+            } if is_odd(crossings_down) => (row, width - 1 + shift - column),
+            Topology::KleinBottle {
+                twisted: Edges::Vertical,
+                shift,
+            } if is_odd(crossings_across) => (height - 1 + shift - row, column),
+            Topology::KleinBottle { .. } => (row, column),
+            Topology::CrossSurface => (
+                if is_odd(crossings_across) {
+                    height - 1 - row
+                } else {
+                    row
+                },
+                if is_odd(crossings_down) {
+                    width - 1 - column
+                } else {
+                    column
+                },
+            ),
+            Topology::Sphere => {
+                let is_inside = |n: isize| (0..width).contains(&n);
+                match (is_inside(row), is_inside(column)) {
+                    (true, true) => (row, column),
+                    (false, true) if row < 0 => (column, -row - 1),
+                    (false, true) => (column, 2 * width - 1 - row),
+                    (true, false) if column < 0 => (-column - 1, row),
+                    (true, false) => (2 * width - 1 - column, row),
+                    (false, false) => return None,
+                }
+            }
+        };
+
+        Some(Cell::new(
+            top + row.rem_euclid(height),
+            left + column.rem_euclid(width),
+        ))
+    }
+
+    /// Parses a Golly bounded grid suffix, such as `T80,60`, `K80*+1,60` or `S50`, returning the
+    /// topology with the width and height of the grid.
+    pub fn parse_grid(s: &str) -> Option<(Topology, usize, usize)> {
+        let mut chars = s.chars();
+        let tag = chars.next()?;
+        let (width, height) = match chars.as_str().split_once(',') {
+            Some((width, height)) => (width, height),
+            None if tag == 'S' => (chars.as_str(), chars.as_str()),
+            None => return None,
+        };
+
+        let dimension = |s: &str| {
+            let (size, shift) = match s.split_once(['+', '-']) {
+                Some((size, _)) => (size, Some(s[size.len()..].parse::<isize>().ok()?)),
+                None => (s, None),
+            };
+            let (size, is_twisted) = match size.strip_suffix('*') {
+                Some(size) => (size, true),
+                None => (size, false),
+            };
+            let size = size.parse::<usize>().ok().filter(|s| *s > 0)?;
+            Some((size, is_twisted, shift))
+        };
+        let (width, is_width_twisted, width_shift) = dimension(width)?;
+        let (height, is_height_twisted, height_shift) = dimension(height)?;
+        let is_plain = !is_width_twisted && !is_height_twisted;
+        let is_unshifted = width_shift.is_none() && height_shift.is_none();
+
+        let topology = match tag {
+            'P' if is_plain && is_unshifted => Topology::Plane,
+            'T' if is_plain && is_unshifted => Topology::Torus,
+            'K' if is_width_twisted && !is_height_twisted && height_shift.is_none() => {
+                Topology::KleinBottle {
+                    twisted: Edges::Horizontal,
+                    shift: width_shift.unwrap_or_default(),
+                }
+            }
+            'K' if is_height_twisted && !is_width_twisted && width_shift.is_none() => {
+                Topology::KleinBottle {
+                    twisted: Edges::Vertical,
+                    shift: height_shift.unwrap_or_default(),
+                }
+            }
+            'C' if is_plain && is_unshifted => Topology::CrossSurface,
+            'S' if is_plain && is_unshifted && width == height => Topology::Sphere,
+            _ => return None,
+        };
+
+        Some((topology, width, height))
+    }
+
+    /// The Golly bounded grid suffix for a grid of the given width and height.
+    pub fn grid(&self, width: usize, height: usize) -> String {
+        let shifted = |size: usize, shift: isize| match shift {
+            0 => format!("{}*", size),
+            shift => format!("{}*{:+}", size, shift),
+        };
+        match self {
+            Topology::Plane => format!("P{},{}", width, height),
+            Topology::Torus => format!("T{},{}", width, height),
+            Topology::KleinBottle {
+                twisted: Edges::Horizontal,
+                shift,
+            } => format!("K{},{}", shifted(width, *shift), height),
+            Topology::KleinBottle {
+                twisted: Edges::Vertical,
+                shift,
+            } => format!("K{},{}", width, shifted(height, *shift)),
+            Topology::CrossSurface => format!("C{},{}", width, height),
+            Topology::Sphere => format!("S{}", width),
+        }
+    }
+}
+
+impl std::fmt::Display for Topology {
+    /// The name that the topology is parsed from, such as `klein-bottle`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Topology::Plane => "plane",
+            Topology::Torus => "torus",
+            Topology::KleinBottle { .. } => "klein-bottle",
+            Topology::CrossSurface => "cross-surface",
+            Topology::Sphere => "sphere",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Topology {
+    type Err = TopologyError;
+
+    /// Accepts `plane`, `torus`, `klein-bottle` (with its top and bottom edges twisted),
+    /// `cross-surface` and `sphere`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "plane" => Ok(Topology::Plane),
+            "torus" => Ok(Topology::Torus),
+            "klein-bottle" => Ok(Topology::KleinBottle {
+                twisted: Edges::Horizontal,
+                shift: 0,
+            }),
+            "cross-surface" => Ok(Topology::CrossSurface),
+            "sphere" => Ok(Topology::Sphere),
+            _ => Err(TopologyError::InvalidTopology(s.into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn grids_round_trip() {
+        for grid in ["P20,10", "T20,10", "K20*,10", "K20,10*-1", "C20,10", "S20"] {
+            let (topology, width, height) = Topology::parse_grid(grid).expect("valid grid");
+            assert_eq!(topology.grid(width, height), grid);
+        }
+    }
+}
@@ -1,35 +1,98 @@
-use std::{collections::HashSet, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+    path::Path,
+    sync::OnceLock,
+};
 
+use rand::{RngExt, SeedableRng, rngs::StdRng};
+use rayon::prelude::*;
 use thiserror::*;
 
 use super::{
+    algorithm::Algorithm,
     bounds::Bounds,
     cell::Cell,
     cells::{Cells, CellsError},
+    connectivity::{Connectivity, placed},
+    engine::{Engine, top_left},
+    format::{FormatError, Life105, Life106, Macrocell, Plaintext, Rle},
+    hashlife::HashLife,
+    neighbourhood::Neighbourhood,
+    orientation::Orientation,
     pattern::Pattern,
     position::Position,
+    rule::Rule,
+    search::Search,
+    states::CellStates,
+    tiles::Tiles,
+    topology::Topology,
 };
 
+const NEIGHBOURHOOD: usize = 8;
+
+/// The most groups of neighbouring cells in a still life that are tried for separate still lifes.
+const MAX_ISLANDS: usize = 12;
+
+/// The least height of the bands of rows that are stepped in parallel.
+const BAND_HEIGHT: isize = 16;
+
+/// The smallest population for which the automatic algorithm considers tiles.
+const AUTO_TILES_POPULATION: usize = 1024;
+
+/// The inverse of the smallest density, over the cells' bounding box, for which the automatic
+/// algorithm chooses tiles.
+const AUTO_TILES_SPARSENESS: usize = 16;
+
 #[derive(Debug, Error)]
 pub enum WorldError {
     #[error("cannot construct world: {0}")]
     BadPath(#[from] CellsError),
+
+    #[error("cannot construct world: {0}")]
+    BadFormat(#[from] FormatError),
+
+    #[error("cannot join the edges of the bounds as a {0}")]
+    IncompatibleTopology(Topology),
+}
+
+/// What holds the world's live cells between generations: the set of cells, or the engine that
+/// stepped them last, from which the set is produced when it is wanted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Holder {
+    Cells,
+    Tiles,
+    HashLife,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct World {
-    live_cells: Cells,
+    live_cells: OnceLock<Cells>,
+    holder: Holder,
     bounds: Bounds,
     viewport: Bounds,
+    rule: Rule,
+    background: bool,
+    decaying_cells: CellStates,
+    topology: Topology,
+    algorithm: Algorithm,
+    hashlife: Option<HashLife>,
+    tiles: Option<Tiles>,
 }
 
 impl World {
     pub fn random() -> Self {
+        Self::random_with_seed(rand::random())
+    }
+
+    /// A random world, the same for the same `seed`.
+    pub fn random_with_seed(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
         let mut cells = Cells::default();
         let bounds = Bounds::new(0..=20, 0..=20);
         bounds.rows().clone().for_each(|r| {
             bounds.columns().clone().for_each(|c| {
-                if rand::random::<f32>() < 0.2 {
+                if rng.random::<f32>() < 0.2 {
                     cells.insert(Cell::new(r, c));
                 }
             })
@@ -37,21 +100,72 @@ impl World {
         World::from(cells)
     }
 
+    /// The cells whose state differs from the background; these are the live cells unless a B0
+    /// rule has brought the unbounded background to life.
     pub fn live_cells(&self) -> &Cells {
-        &self.live_cells
+        self.live_cells.get_or_init(|| match self.holder {
+            Holder::Tiles => self.tiles.as_ref().expect("tiles hold the cells").cells(),
+            Holder::HashLife => self
+                .hashlife
+                .as_ref()
+                .expect("hashlife holds the cells")
+                .cells(),
+            Holder::Cells => Cells::default(),
+        })
+    }
+
+    /// The live cells, to be changed; they are held as a set from then on.
+    fn live_cells_mut(&mut self) -> &mut Cells {
+        self.live_cells();
+        self.holder = Holder::Cells;
+        self.live_cells.get_mut().expect("live cells produced")
+    }
+
+    fn with_live_cells(&mut self, cells: Cells) {
+        self.live_cells = OnceLock::from(cells);
+        self.holder = Holder::Cells;
+    }
+
+    pub fn is_background_live(&self) -> bool {
+        self.background
+    }
+
+    /// The cells passing through the decaying states of a multi-state rule.
+    pub fn decaying_cells(&self) -> &CellStates {
+        &self.decaying_cells
+    }
+
+    /// The state of `cell`: 0 when dead, 1 when live, and 2 or more when decaying.
+    pub fn state(&self, cell: &Cell) -> u8 {
+        if self.is_live(cell) {
+            1
+        } else {
+            self.decaying_cells.state(cell)
+        }
     }
 
     fn remove_off_worlders(&mut self) {
         if self.bounds.is_defined() {
-            let (rows, columns) = (self.bounds.rows(), self.bounds.columns());
-            self.live_cells
-                .retain(|c| rows.contains(&c.row()) && columns.contains(&c.column()));
+            let bounds = self.bounds.clone();
+            match (self.holder, self.tiles.as_mut()) {
+                (Holder::Tiles, Some(tiles)) => {
+                    tiles.clip(&bounds);
+                    self.live_cells = OnceLock::new();
+                }
+                _ => self.live_cells_mut().retain(|c| bounds.contains(c)),
+            }
+            self.decaying_cells.retain(|c, _| bounds.contains(c));
         }
     }
 
-    pub fn with_bounds(&mut self, bounds: &Bounds) {
+    /// Bounds the world, if its edges can be joined as its topology requires.
+    pub fn with_bounds(&mut self, bounds: &Bounds) -> Result<(), WorldError> {
+        if !self.topology.is_valid_for(bounds) {
+            return Err(WorldError::IncompatibleTopology(self.topology));
+        }
         self.bounds = bounds.clone();
         self.remove_off_worlders();
+        Ok(())
     }
 
     pub fn bounds(&self) -> &Bounds {
@@ -66,70 +180,667 @@ impl World {
         &self.viewport
     }
 
+    pub fn with_rule(&mut self, rule: &Rule) {
+        self.rule = *rule;
+        self.decaying_cells.retain(|_, s| *s < rule.states());
+    }
+
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// Joins the edges of the bounds as `topology`, if they can be joined that way.
+    pub fn with_topology(&mut self, topology: &Topology) -> Result<(), WorldError> {
+        if !topology.is_valid_for(&self.bounds) {
+            return Err(WorldError::IncompatibleTopology(*topology));
+        }
+        self.topology = *topology;
+        Ok(())
+    }
+
+    pub fn topology(&self) -> &Topology {
+        &self.topology
+    }
+
+    pub fn with_algorithm(&mut self, algorithm: &Algorithm) {
+        self.algorithm = *algorithm;
+    }
+
+    pub fn algorithm(&self) -> &Algorithm {
+        &self.algorithm
+    }
+
+    fn is_hashlife_supported(&self) -> bool {
+        self.algorithm == Algorithm::HashLife
+            && !self.bounds.is_defined()
+            && !self.background
+            && self.decaying_cells.is_empty()
+            && HashLife::supports(&self.rule)
+    }
+
+    fn is_tiles_supported(&self) -> bool {
+        (!self.bounds.is_defined() || self.topology == Topology::Plane)
+            && !self.background
+            && self.decaying_cells.is_empty()
+            && Tiles::supports(&self.rule)
+    }
+
+    fn is_tiles_chosen(&self) -> bool {
+        match self.algorithm {
+            Algorithm::Tiles => self.is_tiles_supported(),
+            Algorithm::Auto if self.holder == Holder::Tiles => self.is_tiles_supported(),
+            Algorithm::Auto => {
+                let population = self.live_cells().len();
+                let bounds = self.live_cells().bounds();
+                population >= AUTO_TILES_POPULATION
+                    && population * AUTO_TILES_SPARSENESS >= bounds.width() * bounds.height()
+                    && self.is_tiles_supported()
+            }
+            _ => false,
+        }
+    }
+
+    /// Steps the world with tiles, if they are chosen and supported. The tiles go on holding the
+    /// cells between steps, and are only rebuilt once the cells or rule have changed. Cells
+    /// leaving a bounded plane are lost, so bounded worlds are clipped after every generation.
+    fn step_with_tiles(&mut self, generations: u64) -> bool {
+        if !self.is_tiles_chosen() {
+            return false;
+        }
+
+        let mut tiles = match self.tiles.take() {
+            Some(tiles) if self.holder == Holder::Tiles && tiles.rule() == &self.rule => tiles,
+            _ => {
+                let mut tiles = Tiles::new(&self.rule).expect("supported rule");
+                tiles.with_cells(self.live_cells());
+                tiles
+            }
+        };
+        if self.bounds.is_defined() {
+            for _ in 0..generations {
+                tiles.step(1);
+                tiles.clip(&self.bounds);
+            }
+        } else {
+            tiles.step(generations);
+        }
+        self.tiles = Some(tiles);
+        self.holder = Holder::Tiles;
+        self.live_cells = OnceLock::new();
+        true
+    }
+
+    /// Steps the world with HashLife, if it is chosen and supported. The HashLife universe goes
+    /// on holding the cells between steps, and is only rebuilt, keeping its memoised nodes, once
+    /// the cells or rule have changed.
+    fn step_with_hashlife(&mut self, generations: u64) -> bool {
+        if !self.is_hashlife_supported() {
+            return false;
+        }
+
+        let mut hashlife = match self.hashlife.take() {
+            Some(hashlife) if self.holder == Holder::HashLife && hashlife.rule() == &self.rule => {
+                hashlife
+            }
+            hashlife => {
+                let mut hashlife = hashlife
+                    .filter(|hashlife| hashlife.rule() == &self.rule)
+                    .unwrap_or_else(|| HashLife::new(&self.rule).expect("supported rule"));
+                hashlife.with_cells(self.live_cells());
+                hashlife
+            }
+        };
+        hashlife
+            .step(generations)
+            .expect("universe within the coordinates of its cells");
+        self.hashlife = Some(hashlife);
+        self.holder = Holder::HashLife;
+        self.live_cells = OnceLock::new();
+        true
+    }
+
     fn is_live(&self, cell: &Cell) -> bool {
-        self.live_cells.contains(cell)
+        self.live_cells().contains(cell) != self.background
     }
 
-    fn neighbour_count(&self, cell: &Cell) -> usize {
-        cell.neighbours().filter(|cell| self.is_live(cell)).count()
+    /// The cell within the bounds that `cell` stands for, once the edges are joined.
+    fn wrap(&self, cell: &Cell) -> Option<Cell> {
+        self.topology.wrap(&self.bounds, cell)
     }
 
-    pub fn next_generation(&mut self) {
-        let cells_to_consider =
-            self.live_cells
-                .iter()
-                .copied()
-                .fold(HashSet::new(), |mut acc, c| {
-                    acc.insert(c);
-                    acc.extend(c.neighbours());
-                    acc
+    fn neighbourhood(&self, cell: &Cell) -> Neighbourhood {
+        Neighbourhood::of(cell, |c| {
+            self.wrap(c).is_some_and(|c| self.live_cells().contains(&c))
+        })
+    }
+
+    fn is_edge_joined(&self) -> bool {
+        self.bounds.is_defined() && self.topology != Topology::Plane
+    }
+
+    /// How many rows or columns away the neighbours of a cell may be.
+    fn reach(&self) -> isize {
+        self.rule
+            .larger_than_life()
+            .map_or(1, |r| r.range() as isize)
+    }
+
+    /// The cells that will differ from the next background (true) and the cells that will start
+    /// to decay (false). The world is partitioned into bands of rows that are stepped in
+    /// parallel; each band accumulates the neighbours of its own cells, once, from the live cells
+    /// within reach, so the bands are independent of one another and of the threads sharing
+    /// them. Joined edges carry neighbours across the whole world, which is then a single band.
+    fn outcomes(&self, next_background: bool) -> Vec<(Cell, bool)> {
+        let offsets = self.rule.offsets();
+        if self.is_edge_joined() {
+            let cells = self.live_cells().iter().collect::<Vec<_>>();
+            return self.band_outcomes(&cells, isize::MIN..=isize::MAX, &offsets, next_background);
+        }
+
+        let height = BAND_HEIGHT.max(self.reach());
+        let mut bands: HashMap<isize, Vec<&Cell>> = HashMap::new();
+        for cell in self.live_cells().iter() {
+            bands
+                .entry(cell.row().div_euclid(height))
+                .or_default()
+                .push(cell);
+        }
+
+        let mut bands_to_step = bands
+            .keys()
+            .flat_map(|b| b - 1..=b + 1)
+            .collect::<HashSet<_>>();
+        if self.bounds.is_defined() && self.rule.is_born(0) {
+            let rows = self.bounds.rows();
+            bands_to_step.extend(rows.start().div_euclid(height)..=rows.end().div_euclid(height));
+        }
+
+        bands_to_step
+            .into_par_iter()
+            .flat_map_iter(|band| {
+                let cells = (band - 1..=band + 1)
+                    .filter_map(|b| bands.get(&b))
+                    .flatten()
+                    .copied()
+                    .collect::<Vec<_>>();
+                let rows = band * height..=(band + 1) * height - 1;
+                self.band_outcomes(&cells, rows, &offsets, next_background)
+            })
+            .collect()
+    }
+
+    /// The outcomes of the cells in `rows`, given the live cells within reach of them.
+    fn band_outcomes(
+        &self,
+        cells: &[&Cell],
+        rows: RangeInclusive<isize>,
+        offsets: &[Position],
+        next_background: bool,
+    ) -> Vec<(Cell, bool)> {
+        let rule = &self.rule;
+        match rule.larger_than_life() {
+            Some(ltl) => {
+                let is_middle_counted = ltl.is_middle_included();
+                let counts = self.range_counts(cells, &rows, offsets);
+                self.band_outcomes_with(counts, &rows, next_background, |count, is_live| {
+                    let count = count + usize::from(is_live && is_middle_counted);
+                    if is_live {
+                        rule.survives(count)
+                    } else {
+                        rule.is_born(count)
+                    }
+                })
+            }
+            None => {
+                let masks = self.neighbourhoods(cells, &rows);
+                self.band_outcomes_with(masks, &rows, next_background, |mask, is_live| {
+                    let neighbourhood = Neighbourhood::new(mask);
+                    let neighbourhood = if self.background {
+                        neighbourhood.complement()
+                    } else {
+                        neighbourhood
+                    };
+                    if is_live {
+                        rule.survives_with(neighbourhood)
+                    } else {
+                        rule.is_born_with(neighbourhood)
+                    }
+                })
+            }
+        }
+    }
+
+    fn band_outcomes_with<N>(
+        &self,
+        mut neighbours: HashMap<Cell, N>,
+        rows: &RangeInclusive<isize>,
+        next_background: bool,
+        is_next_live: impl Fn(N, bool) -> bool,
+    ) -> Vec<(Cell, bool)>
+    where
+        N: Copy + Default,
+    {
+        if self.bounds.is_defined() && self.rule.is_born(0) {
+            let rows = *rows.start().max(self.bounds.rows().start())
+                ..=*rows.end().min(self.bounds.rows().end());
+            let columns = self.bounds.columns();
+            rows.flat_map(|r| columns.clone().map(move |c| Cell::new(r, c)))
+                .for_each(|c| {
+                    neighbours.entry(c).or_default();
                 });
+        }
 
-        let cell_counts = cells_to_consider
+        let is_decaying = self.rule.states() > 2;
+        neighbours
             .into_iter()
-            .map(|c| (c, self.neighbour_count(&c)))
-            .collect::<Vec<_>>();
-
-        cell_counts.iter().for_each(|(cell, count)| {
-            let _ = match count {
-                0 | 1 => self.live_cells.remove(cell),
-                2 => false,
-                3 => self.live_cells.insert(*cell),
-                _ => self.live_cells.remove(cell),
+            .filter(|(c, _)| !self.decaying_cells.contains_key(c))
+            .filter_map(|(c, n)| {
+                let is_live = self.is_live(&c);
+                if is_next_live(n, is_live) != next_background {
+                    Some((c, true))
+                } else if is_live && is_decaying {
+                    Some((c, false))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// The neighbourhood mask of every cell in `rows` that is live or has a live neighbour, with
+    /// each live cell adding itself to the neighbourhoods around it. Joined edges may reflect the
+    /// cells beyond them, so there the neighbourhoods are gathered from around each cell instead.
+    fn neighbourhoods(&self, cells: &[&Cell], rows: &RangeInclusive<isize>) -> HashMap<Cell, u8> {
+        let mut masks = HashMap::new();
+        if self.is_edge_joined() {
+            for cell in cells.iter() {
+                for c in cell
+                    .neighbours()
+                    .filter_map(|n| self.wrap(&n))
+                    .chain([**cell])
+                {
+                    masks
+                        .entry(c)
+                        .or_insert_with(|| self.neighbourhood(&c).mask());
+                }
+            }
+            return masks;
+        }
+
+        for cell in cells.iter().filter(|c| rows.contains(&c.row())) {
+            masks.entry(**cell).or_default();
+        }
+        for cell in cells.iter() {
+            for (i, neighbour) in cell.neighbours().enumerate() {
+                if rows.contains(&neighbour.row()) {
+                    *masks.entry(neighbour).or_default() |= 1 << (NEIGHBOURHOOD - 1 - i);
+                }
+            }
+        }
+        masks
+    }
+
+    /// The number of live cells within range of every cell in `rows` that is live or has any.
+    fn range_counts(
+        &self,
+        cells: &[&Cell],
+        rows: &RangeInclusive<isize>,
+        offsets: &[Position],
+    ) -> HashMap<Cell, usize> {
+        let mut counts = HashMap::new();
+        for cell in cells.iter().filter(|c| rows.contains(&c.row())) {
+            counts.entry(**cell).or_default();
+        }
+        for cell in cells.iter() {
+            for neighbour in offsets.iter().filter_map(|o| self.wrap(&(**cell + *o))) {
+                if rows.contains(&neighbour.row()) {
+                    *counts.entry(neighbour).or_default() += 1;
+                }
+            }
+        }
+        counts
+    }
+
+    /// Rules with B0 bring every empty cell of an unbounded world to life. Rather than the
+    /// infinite set of live cells, the cells differing from the (possibly alternating) background
+    /// are tracked, and the rule is applied to the complement when the background is alive.
+    pub fn next_generation(&mut self) {
+        if self.step_with_hashlife(1) || self.step_with_tiles(1) {
+            return;
+        }
+
+        let next_background = !self.bounds.is_defined()
+            && if self.background {
+                self.rule.survives(NEIGHBOURHOOD)
+            } else {
+                self.rule.is_born(0)
             };
-        });
+        let outcomes = self.outcomes(next_background);
 
+        let states = self.rule.states();
+        let mut next_decaying_cells = self
+            .decaying_cells
+            .iter()
+            .filter(|(_, s)| **s + 1 < states)
+            .map(|(c, s)| (*c, s + 1))
+            .collect::<CellStates>();
+        let mut next_cells = Cells::default();
+        for (c, is_differing) in outcomes {
+            if is_differing {
+                next_cells.insert(c);
+            } else {
+                next_decaying_cells.insert(c, 2);
+            }
+        }
+
+        self.with_live_cells(next_cells);
+        self.decaying_cells = next_decaying_cells;
+        self.background = next_background;
         self.remove_off_worlders();
     }
 
+    /// Advances the world by `generations`, jumping straight there when HashLife or tiles are
+    /// chosen and supported.
+    pub fn step_n(&mut self, generations: u64) {
+        if generations == 0
+            || self.step_with_hashlife(generations)
+            || self.step_with_tiles(generations)
+        {
+            return;
+        }
+        (0..generations).for_each(|_| self.next_generation());
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.live_cells.is_empty()
+        Engine::population(self) == 0 && self.decaying_cells.is_empty() && !self.background
     }
 
     pub fn add_cells(&mut self, cells: Cells, offset: &Position) {
         cells.iter().map(|c| *c + *offset).for_each(|c| {
-            self.live_cells.insert(c);
+            if self.background {
+                self.live_cells_mut().remove(&c);
+            } else {
+                self.live_cells_mut().insert(c);
+            }
         });
         self.remove_off_worlders();
     }
+
+    /// The live cells split into the objects they make up, each moved to the origin, with the
+    /// position it was moved from. Still lifes that touch but would each be still on their own,
+    /// making a pseudo still life, are separated too.
+    pub fn objects(&self, connectivity: &Connectivity) -> Vec<(Cells, Position)> {
+        let objects = connectivity
+            .components(self.live_cells())
+            .into_iter()
+            .flat_map(|object| {
+                if self.is_still(&object) {
+                    self.still_lifes(&object)
+                } else {
+                    vec![object]
+                }
+            });
+        placed(objects)
+    }
+
+    /// Where the searched for pattern appears among the live cells, as the top left of its
+    /// bounds, and in which orientation.
+    pub fn find(&self, search: &Search) -> Vec<(Position, Orientation)> {
+        self.live_cells().find(search)
+    }
+
+    /// Whether `cells`, on their own, stay as they are under the world's rule.
+    fn is_still(&self, cells: &Cells) -> bool {
+        let mut world = World::from(cells.clone());
+        world.with_rule(&self.rule);
+        world.next_generation();
+        world.live_cells() == cells
+    }
+
+    /// The still life split into the most still lifes that it can be, made up of the groups of
+    /// neighbouring cells it has; those of more than [MAX_ISLANDS] groups are left whole.
+    fn still_lifes(&self, still_life: &Cells) -> Vec<Cells> {
+        let islands = Connectivity::Neighbours.components(still_life);
+        if islands.len() < 2 || islands.len() > MAX_ISLANDS {
+            return vec![still_life.clone()];
+        }
+
+        let union = |mask: u32| {
+            let chosen = islands
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & 1 << i != 0);
+            Cells::from_iter(chosen.flat_map(|(_, island)| island.iter().copied()))
+        };
+
+        // The smallest still life including the first group, whose remainder is still too.
+        let all = (1u32 << islands.len()) - 1;
+        let mut masks = Vec::from_iter((0..all >> 1).map(|others| others << 1 | 1));
+        masks.sort_by_key(|mask| mask.count_ones());
+        let split = masks.into_iter().find_map(|mask| {
+            let (part, rest) = (union(mask), union(all & !mask));
+            (self.is_still(&part) && self.is_still(&rest)).then_some((part, rest))
+        });
+
+        match split {
+            Some((part, rest)) => {
+                let mut still_lifes = self.still_lifes(&part);
+                still_lifes.extend(self.still_lifes(&rest));
+                still_lifes
+            }
+            None => vec![still_life.clone()],
+        }
+    }
+
+    /// Sets the state of `cell`, when it lies within the bounds.
+    fn with_cell(&mut self, cell: &Cell, is_live: bool) {
+        if self.bounds.is_defined() && !self.bounds.contains(cell) {
+            return;
+        }
+
+        self.decaying_cells.remove(cell);
+        let is_differing = is_live != self.background;
+        match (self.holder, self.tiles.as_mut(), self.hashlife.as_mut()) {
+            (Holder::Tiles, Some(tiles), _) => tiles.with_cell(cell, is_differing),
+            (Holder::HashLife, _, Some(hashlife)) => hashlife.with_cell(cell, is_differing),
+            _ => {
+                self.live_cells_mut();
+            }
+        }
+        if let Some(cells) = self.live_cells.get_mut() {
+            if is_differing {
+                cells.insert(*cell);
+            } else {
+                cells.remove(cell);
+            }
+        }
+    }
+
+    /// Writes the world to `path`, in the format implied by its extension.
+    pub fn save(&self, path: &Path) -> Result<(), WorldError> {
+        if self.background {
+            Err(FormatError::LiveBackground)?;
+        }
+
+        match extension(path).as_str() {
+            "rle" => Rle::try_from(self)?.save(path)?,
+            "cells" => Plaintext::from(self.live_cells()).save(path)?,
+            "lif" => Life106::from(self.live_cells()).save(path)?,
+            "mc" => Macrocell::try_from(self)?.save(path)?,
+            other => Err(FormatError::UnsupportedExtension(other.into()))?,
+        }
+        Ok(())
+    }
+}
+
+impl Engine for World {
+    type Snapshot = (Cells, CellStates, bool);
+
+    fn step(&mut self) {
+        self.next_generation();
+    }
+
+    fn step_n(&mut self, generations: u64) {
+        World::step_n(self, generations);
+    }
+
+    /// The number of cells differing from the background.
+    fn population(&self) -> usize {
+        match (self.holder, &self.tiles, &self.hashlife) {
+            (Holder::Tiles, Some(tiles), _) => tiles.population() as usize,
+            (Holder::HashLife, _, Some(hashlife)) => hashlife.population() as usize,
+            _ => self.live_cells().len(),
+        }
+    }
+
+    fn bounding_box(&self) -> Bounds {
+        self.live_cells().bounds()
+    }
+
+    fn cells_in(&self, rect: &Bounds) -> Cells {
+        Cells::from_iter(
+            self.live_cells()
+                .iter()
+                .filter(|c| rect.contains(c))
+                .copied(),
+        )
+    }
+
+    fn set_cell(&mut self, cell: &Cell) {
+        self.with_cell(cell, true);
+    }
+
+    fn clear_cell(&mut self, cell: &Cell) {
+        self.with_cell(cell, false);
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (
+            self.live_cells().clone(),
+            self.decaying_cells.clone(),
+            self.background,
+        )
+    }
+
+    /// The live and decaying cells are moved together, by the bounding box of both.
+    fn normalised_snapshot(&self) -> Option<(Self::Snapshot, Position)> {
+        if self.bounds.is_defined() {
+            return None;
+        }
+
+        let mut cells = self.live_cells().clone();
+        cells.extend(self.decaying_cells.keys().copied());
+        let origin = top_left(&cells);
+        let offset = Position::new(-origin.row(), -origin.column());
+        let live_cells = Cells::from_iter(self.live_cells().iter().map(|c| *c + offset));
+        let decaying_cells = self
+            .decaying_cells
+            .iter()
+            .map(|(c, s)| (*c + offset, *s))
+            .collect::<CellStates>();
+        Some(((live_cells, decaying_cells, self.background), origin))
+    }
+}
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase()
 }
 
 impl From<Cells> for World {
     fn from(value: Cells) -> Self {
         Self {
-            live_cells: value,
+            live_cells: OnceLock::from(value),
+            holder: Holder::Cells,
             bounds: Bounds::default(),
             viewport: Bounds::default(),
+            rule: Rule::default(),
+            background: false,
+            decaying_cells: CellStates::default(),
+            topology: Topology::default(),
+            algorithm: Algorithm::default(),
+            hashlife: None,
+            tiles: None,
         }
     }
 }
 
+impl PartialEq for World {
+    /// Worlds are equal when their cells and settings are, however they are computed.
+    fn eq(&self, other: &Self) -> bool {
+        self.live_cells() == other.live_cells()
+            && self.bounds == other.bounds
+            && self.viewport == other.viewport
+            && self.rule == other.rule
+            && self.background == other.background
+            && self.decaying_cells == other.decaying_cells
+            && self.topology == other.topology
+    }
+}
+
+impl From<Rle> for World {
+    fn from(value: Rle) -> Self {
+        let bounds = value.bounds().cloned();
+        let rule = value.rule().copied();
+        let decaying_cells = value.decaying_cells().clone();
+        let topology = *value.topology();
+        let mut world = Self::from(Cells::from(value));
+        world.decaying_cells = decaying_cells;
+        world.topology = topology;
+        if let Some(bounds) = bounds {
+            world
+                .with_bounds(&bounds)
+                .expect("grids of spheres are square");
+        }
+        if let Some(rule) = rule {
+            world.with_rule(&rule);
+        }
+        world
+    }
+}
+
+impl From<Life105> for World {
+    fn from(value: Life105) -> Self {
+        let rule = value.rule().copied().unwrap_or_default();
+        let mut world = Self::from(Cells::from(value));
+        world.with_rule(&rule);
+        world
+    }
+}
+
+impl From<Macrocell> for World {
+    fn from(value: Macrocell) -> Self {
+        let rule = value.rule().copied().unwrap_or_default();
+        let mut world = Self::from(Cells::from(value));
+        world.with_rule(&rule);
+        world
+    }
+}
+
 impl TryFrom<&Path> for World {
     type Error = WorldError;
 
     fn try_from(value: &Path) -> Result<Self, Self::Error> {
-        let cells = Cells::try_from(value)?;
-        Ok(Self::from(cells))
+        match extension(value).as_str() {
+            "rle" => Ok(Self::from(Rle::try_from(value)?)),
+            "cells" => Ok(Self::from(Cells::from(Plaintext::try_from(value)?))),
+            "mc" => Ok(Self::from(Macrocell::try_from(value)?)),
+            "lif" | "life" => {
+                let content = std::fs::read_to_string(value).map_err(CellsError::from)?;
+                if content.starts_with(Life105::HEADER) {
+                    Ok(Self::from(Life105::try_from(content.as_str())?))
+                } else if content.starts_with(Life106::HEADER) {
+                    Ok(Self::from(Cells::from(Life106::try_from(
+                        content.as_str(),
+                    )?)))
+                } else {
+                    Ok(Self::from(Cells::try_from(content.as_str())?))
+                }
+            }
+            _ => Ok(Self::from(Cells::try_from(value)?)),
+        }
     }
 }
 
@@ -144,7 +855,10 @@ impl TryFrom<&Pattern> for World {
 
 impl std::fmt::Display for World {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let default_bounds = self.live_cells.bounds();
+        let mut default_bounds = self.live_cells().bounds();
+        self.decaying_cells
+            .keys()
+            .for_each(|c| default_bounds.encompass(c));
         let bounds = if self.viewport.is_defined() {
             &self.viewport
         } else if self.bounds.is_defined() {
@@ -159,12 +873,12 @@ impl std::fmt::Display for World {
         let pretty_row = |r: isize| {
             columns
                 .clone()
-                .map(|c| {
-                    if self.is_live(&Cell::new(r, c)) {
-                        "*"
-                    } else {
-                        " "
-                    }
+                .map(|c| match self.state(&Cell::new(r, c)) {
+                    0 => " ",
+                    1 => "*",
+                    2 => "+",
+                    s if s + 1 == self.rule.states() => ".",
+                    _ => "-",
                 })
                 .collect::<Vec<_>>()
                 .join(" ")
@@ -191,4 +905,50 @@ mod test {
         let error = World::try_from(path).unwrap_err();
         assert!(matches!(error, WorldError::BadPath(_)));
     }
+
+    #[test]
+    fn will_not_create_world_from_invalid_rle_file() {
+        let path = Path::new("./tests/data/invalid_world.rle");
+        let error = World::try_from(path).unwrap_err();
+        assert!(matches!(error, WorldError::BadFormat(_)));
+    }
+
+    #[test]
+    fn will_not_save_world_to_unknown_format() {
+        let path = std::env::temp_dir().join("life_world_spec.unknown");
+        let error = World::from(Cells::default()).save(&path).unwrap_err();
+        assert!(matches!(
+            error,
+            WorldError::BadFormat(FormatError::UnsupportedExtension(_))
+        ));
+    }
+
+    #[test]
+    fn will_keep_the_tiles_between_steps() {
+        let mut world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        world.with_algorithm(&Algorithm::Tiles);
+        (0..3).for_each(|_| world.next_generation());
+        world.step_n(5);
+        assert_eq!(world.tiles.as_ref().map(|t| t.generation()), Some(8));
+
+        world.with_cell(&Cell::new(-50, -50), true);
+        world.next_generation();
+        assert_eq!(world.tiles.as_ref().map(|t| t.generation()), Some(9));
+        assert!(!world.live_cells().contains(&Cell::new(-50, -50)));
+    }
+
+    #[test]
+    fn will_keep_the_hashlife_universe_between_steps() {
+        let mut world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        world.with_algorithm(&Algorithm::HashLife);
+        (0..3).for_each(|_| world.next_generation());
+        world.step_n(5);
+        assert_eq!(world.hashlife.as_ref().map(|h| h.generation()), Some(8));
+
+        world.with_cell(&Cell::new(-50, -50), true);
+        assert!(world.live_cells().contains(&Cell::new(-50, -50)));
+        world.next_generation();
+        assert_eq!(world.hashlife.as_ref().map(|h| h.generation()), Some(9));
+        assert!(!world.live_cells().contains(&Cell::new(-50, -50)));
+    }
 }
@@ -6,7 +6,18 @@ use life::prelude::*;
 #[coverage(off)]
 fn main() -> Result<(), String> {
     let args = Arguments::parse();
-    let mut life = Life::try_from(&args).map_err(|e| e.to_string())?;
-    life.run();
+    if let Some(soups) = args.census() {
+        let mut census = Census::from(&args);
+        census.run(*soups);
+        let report = args
+            .report()
+            .cloned()
+            .unwrap_or_else(|| "census.txt".into());
+        census.save(&report).map_err(|e| e.to_string())?;
+        print!("{}", census);
+    } else {
+        let mut life = Life::try_from(&args).map_err(|e| e.to_string())?;
+        life.run();
+    }
     Ok(())
 }
@@ -0,0 +1,118 @@
+mod apgcode {
+    use clap::ValueEnum;
+    use life::prelude::{
+        Apgcode, ApgcodeError, Cells, Classification, Generations, Pattern, World,
+    };
+    use pretty_assertions::assert_eq;
+
+    /// The pattern's cells once they have entered their cycle, with their classification.
+    fn settled(pattern: &Pattern) -> (Cells, Classification) {
+        let cells = Cells::try_from(pattern.cells_str()).expect("valid pattern");
+        let classification = Classification::of(World::from(cells.clone()), 1000);
+        let mut generations = Generations::new(World::from(cells.clone()));
+        while generations.cycle().is_none() && generations.generation() < 1000 {
+            generations.next_generation();
+        }
+        let mut world = World::from(cells);
+        if let Some(cycle) = generations.cycle() {
+            world.step_n(cycle.entry());
+        }
+        (world.live_cells().clone(), classification)
+    }
+
+    fn apgcode(pattern: &Pattern) -> String {
+        let (cells, classification) = settled(pattern);
+        Apgcode::new(&cells, &classification)
+            .expect("known object")
+            .to_string()
+    }
+
+    #[test]
+    fn should_encode_objects() {
+        assert_eq!(apgcode(&Pattern::Block), "xs4_33");
+        assert_eq!(apgcode(&Pattern::Beehive), "xs6_696");
+        assert_eq!(apgcode(&Pattern::Boat), "xs5_253");
+        assert_eq!(apgcode(&Pattern::Loaf), "xs7_2596");
+        assert_eq!(apgcode(&Pattern::Tub), "xs4_252");
+        assert_eq!(apgcode(&Pattern::Blinker), "xp2_7");
+        assert_eq!(apgcode(&Pattern::Toad), "xp2_7e");
+        assert_eq!(
+            apgcode(&Pattern::Pulsar),
+            "xp3_co9nas0san9oczgoldlo0oldlogz1047210127401"
+        );
+        assert_eq!(apgcode(&Pattern::Glider), "xq4_153");
+        assert_eq!(apgcode(&Pattern::SpaceshipLightweight), "xq4_6frc");
+    }
+
+    #[test]
+    fn should_be_the_same_in_every_phase_and_orientation() {
+        let glider = Cells::try_from(Pattern::Glider.cells_str()).expect("valid pattern");
+        let classification = Classification::of(World::from(glider.clone()), 100);
+        let mut world = World::from(glider);
+        for _ in 0..4 {
+            let mut cells = world.live_cells().clone();
+            for rotation in 0..4 {
+                cells = cells.rotate(1);
+                let code = Apgcode::new(&cells, &classification).expect("spaceship");
+                assert_eq!(code.to_string(), "xq4_153", "rotation {}", rotation);
+            }
+            world.next_generation();
+        }
+    }
+
+    #[test]
+    fn should_round_trip_every_pattern() {
+        for pattern in Pattern::value_variants() {
+            let (cells, classification) = settled(pattern);
+            let Some(code) = Apgcode::new(&cells, &classification) else {
+                assert!(
+                    matches!(classification, Classification::Growing { .. }),
+                    "{:?}",
+                    pattern
+                );
+                continue;
+            };
+
+            let decoded = code.to_string().parse::<Apgcode>().expect("valid apgcode");
+            assert_eq!(decoded, code, "{:?}", pattern);
+            let cells = decoded.cells();
+            let reclassified = Classification::of(World::from(cells.clone()), 1000);
+            let (was, is) = (classification.to_string(), reclassified.to_string());
+            assert_eq!(is, was, "{:?}", pattern);
+            assert_eq!(
+                Apgcode::new(&cells, &reclassified),
+                Some(code),
+                "{:?}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn should_decode_runs_of_zeros_and_strips() {
+        let code = "xp2_0wxy0z1".parse::<Apgcode>().expect("valid apgcode");
+        assert_eq!(code.period(), 2);
+        assert!(!code.is_moving());
+        assert_eq!(code.cells().len(), 1);
+        let cells = code.cells();
+        let cell = cells.iter().next().expect("one cell");
+        assert_eq!((cell.row(), cell.column()), (5, 0));
+
+        let pulsar = "xp3_co9nas0san9oczgoldlo0oldlogz1047210127401"
+            .parse::<Apgcode>()
+            .expect("valid apgcode");
+        assert_eq!(pulsar.cells().len(), 72);
+    }
+
+    #[test]
+    fn should_reject_invalid_codes() {
+        let error = |code: &str| code.parse::<Apgcode>().expect_err("invalid apgcode");
+        assert!(matches!(error("xr4_33"), ApgcodeError::InvalidPrefix(_)));
+        assert!(matches!(error("xs4"), ApgcodeError::InvalidPrefix(_)));
+        assert!(matches!(error("xp0_7"), ApgcodeError::InvalidPrefix(_)));
+        assert_eq!(error("xs4_3$"), ApgcodeError::InvalidCharacter('$'));
+        assert_eq!(error("xs4_3A"), ApgcodeError::InvalidCharacter('A'));
+        assert_eq!(error("xs4_33y"), ApgcodeError::IncompleteRun);
+        assert_eq!(error("xs5_33"), ApgcodeError::PopulationMismatch(5, 4));
+    }
+}
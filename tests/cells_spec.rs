@@ -2,7 +2,14 @@ mod cells {
     use std::path::Path;
 
     use life::prelude::*;
-    use pretty_assertions::assert_eq;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn should_be_bounded_by_the_cells_alone() {
+        let cells = Cells::from_iter([Cell::new(5, 7), Cell::new(6, 9)]);
+        assert_eq!(cells.bounds(), Bounds::new(5..=6, 7..=9));
+        assert!(!Cells::default().bounds().is_defined());
+    }
 
     #[test]
     fn should_be_creatable_when_empty() {
@@ -187,4 +194,127 @@ mod cells {
         let actual_cells = initial_cells.rotate(4);
         assert_eq!(actual_cells, expected_cells);
     }
+
+    fn l_shape() -> Cells {
+        Cells::try_from(
+            r#"
+* * * *
+. . * *
+. . . *
+"#,
+        )
+        .expect("valid cells")
+    }
+
+    #[test]
+    fn flip_horizontally() {
+        let expected_cells = Cells::try_from(
+            r#"
+* * * *
+* * . .
+* . . .
+"#,
+        )
+        .expect("valid cells");
+        assert_eq!(l_shape().flip_horizontal(), expected_cells);
+    }
+
+    #[test]
+    fn flip_vertically() {
+        let expected_cells = Cells::try_from(
+            r#"
+. . . *
+. . * *
+* * * *
+"#,
+        )
+        .expect("valid cells");
+        assert_eq!(l_shape().flip_vertical(), expected_cells);
+    }
+
+    #[test]
+    fn transpose() {
+        let expected_cells = Cells::try_from(
+            r#"
+* . .
+* . .
+* * .
+* * *
+"#,
+        )
+        .expect("valid cells");
+        assert_eq!(l_shape().transpose(), expected_cells);
+    }
+
+    #[test]
+    fn anti_transpose() {
+        let expected_cells = Cells::try_from(
+            r#"
+* * *
+. * *
+. . *
+. . *
+"#,
+        )
+        .expect("valid cells");
+        assert_eq!(l_shape().anti_transpose(), expected_cells);
+    }
+
+    #[test]
+    fn transform_the_same_away_from_the_origin() {
+        let offset = Position::new(-7, 12);
+        for orientation in Orientation::ALL {
+            let moved = l_shape().translate(&offset).transform(&orientation);
+            let moved = moved.normalise();
+            let expected_cells = l_shape().transform(&orientation);
+            assert_eq!(moved, expected_cells, "{}", orientation);
+        }
+    }
+
+    #[test]
+    fn transform_back_with_the_inverse() {
+        for orientation in Orientation::ALL {
+            let there_and_back = l_shape()
+                .transform(&orientation)
+                .transform(&orientation.inverse());
+            assert_eq!(there_and_back, l_shape(), "{}", orientation);
+        }
+    }
+
+    #[test]
+    fn transform_to_eight_distinct_orientations() {
+        let orientations = Orientation::ALL.map(|o| l_shape().transform(&o).normalise());
+        let distinct = std::collections::HashSet::<Cells>::from_iter(orientations);
+        assert_eq!(distinct.len(), 8);
+    }
+
+    #[test]
+    fn translate() {
+        let cells = Cells::from_iter([Cell::new(0, 0), Cell::new(1, 2)]);
+        let expected_cells = Cells::from_iter([Cell::new(-3, 4), Cell::new(-2, 6)]);
+        assert_eq!(cells.translate(&Position::new(-3, 4)), expected_cells);
+    }
+
+    #[test]
+    fn normalise_to_the_origin() {
+        let cells = Cells::from_iter([Cell::new(-3, 4), Cell::new(-2, 6)]);
+        let expected_cells = Cells::from_iter([Cell::new(0, 0), Cell::new(1, 2)]);
+        assert_eq!(cells.normalise(), expected_cells);
+        assert_eq!(Cells::default().normalise(), Cells::default());
+    }
+
+    #[test]
+    fn canonical_form_is_the_same_for_every_orientation_and_position() {
+        let glider = Cells::try_from(Pattern::Glider.cells_str()).expect("valid pattern");
+        let canonical = glider.canonical();
+        for orientation in Orientation::ALL {
+            let moved = glider
+                .clone()
+                .transform(&orientation)
+                .translate(&Position::new(5, -9));
+            assert_eq!(moved.canonical(), canonical, "{}", orientation);
+        }
+        assert_eq!(canonical.bounds(), Bounds::new(0..=2, 0..=2));
+        assert_ne!(canonical, l_shape().canonical());
+    }
 }
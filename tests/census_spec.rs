@@ -0,0 +1,49 @@
+mod census {
+    use life::prelude::{Apgcode, Census};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_be_the_same_for_the_same_seed() {
+        let mut census = Census::new(42);
+        census.run(2);
+        let mut again = Census::new(42);
+        again.run(1);
+        again.run(1);
+        assert_eq!(census.soups(), 2);
+        assert_eq!(again.tally(), census.tally());
+        assert_eq!(again.to_string(), census.to_string());
+    }
+
+    #[test]
+    fn should_tally_the_common_objects() {
+        let mut census = Census::new(0);
+        census.run(3);
+        let objects = census.tally().values().sum::<u64>();
+        assert!(objects > 0);
+        let block = "xs4_33".parse::<Apgcode>().expect("valid apgcode");
+        assert!(census.tally().contains_key(&block));
+        assert_eq!(census.unresolved(), 0);
+    }
+
+    #[test]
+    fn should_count_soups_that_do_not_settle() {
+        let mut census = Census::new(0);
+        census.with_limit(10);
+        census.run(3);
+        assert_eq!(census.unsettled(), 3);
+        assert!(census.tally().is_empty());
+        assert_eq!(census.unresolved(), 0);
+    }
+
+    #[test]
+    fn should_write_a_report() {
+        let mut census = Census::new(7);
+        census.run(1);
+        let path = std::env::temp_dir().join("life_census_spec.txt");
+        census.save(&path).expect("saved report");
+        let report = std::fs::read_to_string(&path).expect("report");
+        assert!(report.starts_with("# Census of 1 soups from seed 7\n"));
+        assert_eq!(report, census.to_string());
+        std::fs::remove_file(path).expect("removed report");
+    }
+}
@@ -0,0 +1,130 @@
+mod classification {
+    use life::prelude::{Bounds, Cell, Cells, Classification, Pattern, Position, Topology, World};
+    use pretty_assertions::assert_eq;
+
+    fn classify(pattern: &Pattern) -> Classification {
+        let world = World::try_from(pattern).expect("valid pattern");
+        Classification::of(world, 1000)
+    }
+
+    #[test]
+    fn should_classify_still_lifes() {
+        // The beacon asset is missing a cell, and settles into a still life.
+        for pattern in [
+            Pattern::Beacon,
+            Pattern::Beehive,
+            Pattern::Block,
+            Pattern::Boat,
+            Pattern::Loaf,
+            Pattern::Tub,
+        ] {
+            assert_eq!(
+                classify(&pattern),
+                Classification::StillLife,
+                "{:?}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn should_classify_oscillators_with_their_period() {
+        for (pattern, period) in [
+            (Pattern::Blinker, 2),
+            (Pattern::Toad, 2),
+            (Pattern::Pulsar, 3),
+            // The penta-decathlon asset is missing a row, and settles into period 2.
+            (Pattern::PentaDecathlon, 2),
+        ] {
+            let expected = Classification::Oscillator { period };
+            assert_eq!(classify(&pattern), expected, "{:?}", pattern);
+        }
+    }
+
+    #[test]
+    fn should_classify_spaceships_with_their_period_and_displacement() {
+        assert_eq!(
+            classify(&Pattern::Glider),
+            Classification::Spaceship {
+                period: 4,
+                displacement: Position::new(-1, 1)
+            }
+        );
+        let Classification::Spaceship {
+            period,
+            displacement,
+        } = classify(&Pattern::SpaceshipLightweight)
+        else {
+            panic!("expected a spaceship")
+        };
+        assert_eq!(period, 4);
+        assert_eq!(displacement.row().abs() + displacement.column().abs(), 2);
+    }
+
+    #[test]
+    fn should_classify_guns_by_their_growth() {
+        let world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        assert_eq!(
+            Classification::of(world, 200),
+            Classification::Growing {
+                period: 30,
+                growth: 5
+            }
+        );
+    }
+
+    #[test]
+    fn should_classify_patterns_that_die() {
+        let world = World::from(Cells::from_iter([Cell::new(0, 0), Cell::new(0, 1)]));
+        assert_eq!(
+            Classification::of(world, 10),
+            Classification::Dies { generation: 1 }
+        );
+        assert_eq!(
+            Classification::of(World::from(Cells::default()), 10),
+            Classification::Dies { generation: 0 }
+        );
+    }
+
+    #[test]
+    fn should_leave_patterns_unresolved_within_the_limit() {
+        let r_pentomino = Cells::try_from(".**\n**.\n.*.").expect("valid cells");
+        assert_eq!(
+            Classification::of(World::from(r_pentomino), 100),
+            Classification::Unresolved
+        );
+    }
+
+    #[test]
+    fn should_classify_spaceships_on_a_torus_as_oscillators() {
+        let mut world = World::try_from(&Pattern::Glider).expect("valid pattern");
+        world
+            .with_bounds(&Bounds::new(0..=7, 0..=7))
+            .expect("bounds suit the topology");
+        world
+            .with_topology(&Topology::Torus)
+            .expect("compatible topology");
+        assert_eq!(
+            Classification::of(world, 100),
+            Classification::Oscillator { period: 32 }
+        );
+    }
+
+    #[test]
+    fn should_display_the_classification() {
+        let glider = classify(&Pattern::Glider);
+        assert_eq!(
+            glider.to_string(),
+            "spaceship of period 4 moving at c/4 diagonal"
+        );
+        let gun = Classification::Growing {
+            period: 30,
+            growth: 5,
+        };
+        assert_eq!(gun.to_string(), "growing by 5 cells every 30 generations");
+        assert_eq!(
+            classify(&Pattern::Pulsar).to_string(),
+            "oscillator of period 3"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+mod engine {
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    fn cells(pattern: &Pattern) -> Cells {
+        Cells::try_from(pattern.cells_str()).expect("valid pattern")
+    }
+
+    fn hashlife(cells: &Cells) -> HashLife {
+        let mut hashlife = HashLife::new(&Rule::default()).expect("supported rule");
+        hashlife.with_cells(cells);
+        hashlife
+    }
+
+    fn tiles(cells: &Cells) -> Tiles {
+        let mut tiles = Tiles::new(&Rule::default()).expect("supported rule");
+        tiles.with_cells(cells);
+        tiles
+    }
+
+    /// The population, bounding box and cells near the origin of `engine`, `generations` on.
+    fn summary<E: Engine>(mut engine: E, generations: u64) -> (usize, Bounds, Cells) {
+        engine.step_n(generations);
+        let rect = Bounds::new(-5..=20, -5..=20);
+        (
+            engine.population(),
+            engine.bounding_box(),
+            engine.cells_in(&rect),
+        )
+    }
+
+    /// Draws a horizontal blinker with `set_cell`, erasing a stray cell with `clear_cell`, and
+    /// returns the cells after one generation.
+    fn drawn_blinker<E: Engine>(mut engine: E) -> Cells {
+        (0..3).for_each(|c| engine.set_cell(&Cell::new(1, c)));
+        engine.set_cell(&Cell::new(10, 10));
+        engine.clear_cell(&Cell::new(10, 10));
+        assert_eq!(engine.population(), 3);
+        engine.step();
+        engine.cells_in(&Bounds::new(-10..=10, -10..=10))
+    }
+
+    #[test]
+    fn should_agree_whatever_the_engine() {
+        let gun = cells(&Pattern::GosperGliderGun);
+        let expected = summary(World::from(gun.clone()), 100);
+        assert_eq!(summary(hashlife(&gun), 100), expected);
+        assert_eq!(summary(tiles(&gun), 100), expected);
+    }
+
+    #[test]
+    fn should_set_and_clear_cells_whatever_the_engine() {
+        let expected = Cells::from_iter((0..3).map(|r| Cell::new(r, 1)));
+        assert_eq!(drawn_blinker(World::from(Cells::default())), expected);
+        assert_eq!(drawn_blinker(hashlife(&Cells::default())), expected);
+        assert_eq!(drawn_blinker(tiles(&Cells::default())), expected);
+    }
+
+    #[test]
+    fn should_set_cells_far_from_a_hashlife_universe() {
+        let mut hashlife = hashlife(&cells(&Pattern::Block));
+        hashlife.set_cell(&Cell::new(-1000, 5000));
+        assert_eq!(Engine::population(&hashlife), 5);
+        assert_eq!(hashlife.bounding_box(), Bounds::new(-1000..=1, 0..=5000),);
+    }
+
+    #[test]
+    fn should_not_set_cells_beyond_the_bounds_of_a_world() {
+        let mut world = World::from(Cells::default());
+        world
+            .with_bounds(&Bounds::new(0..=9, 0..=9))
+            .expect("bounds suit the topology");
+        world.set_cell(&Cell::new(5, 5));
+        world.set_cell(&Cell::new(5, 10));
+        assert_eq!(world.population(), 1);
+    }
+
+    #[test]
+    fn should_generate_with_any_engine() {
+        let mut generations = Generations::new(hashlife(&cells(&Pattern::Blinker)));
+        let count = std::iter::from_fn(|| generations.next_generation().map(|_| ())).count();
+        assert_eq!(count, 2);
+        assert_eq!(generations.current().population(), 3);
+    }
+}
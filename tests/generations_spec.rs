@@ -1,5 +1,7 @@
 mod generations {
-    use life::prelude::{Cell, Cells, Generations, Pattern, World};
+    use life::prelude::{
+        Bounds, Cell, Cells, Cycle, Generations, History, Pattern, Position, Topology, World,
+    };
     use pretty_assertions::{assert_eq, assert_ne};
 
     #[test]
@@ -49,4 +51,137 @@ mod generations {
         };
         assert_eq!(generations.next_generation(), None);
     }
+
+    #[test]
+    fn should_distinguish_worlds_by_decaying_states() {
+        let mut world = World::from(Cells::from_iter([Cell::new(0, 0)]));
+        world.with_rule(&"345/2/4".parse().expect("valid rule"));
+        let mut generations = Generations::new(world);
+        let count = std::iter::from_fn(|| generations.next_generation().cloned()).count();
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn should_advance_many_generations_at_once() {
+        let world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        let mut stepped = Generations::new(world.clone());
+        (0..120).for_each(|_| {
+            stepped.next_generation();
+        });
+
+        let mut advanced = Generations::new(world);
+        let current = advanced.advance(120);
+        assert_eq!(current, stepped.current());
+        assert_eq!(advanced.generation(), 120);
+        assert!(advanced.next_generation().is_some());
+        assert_eq!(advanced.generation(), 121);
+    }
+
+    fn cycle_of(world: World) -> Cycle {
+        let mut generations = Generations::new(world);
+        while generations.cycle().is_none() && generations.next_generation().is_some() {}
+        *generations.cycle().expect("cycle")
+    }
+
+    fn cycle_of_pattern(pattern: &Pattern) -> Cycle {
+        cycle_of(World::try_from(pattern).expect("valid pattern"))
+    }
+
+    #[test]
+    fn should_report_the_period_of_an_oscillator() {
+        assert_eq!(
+            cycle_of_pattern(&Pattern::Block),
+            Cycle::new(0, 1, Position::new(0, 0))
+        );
+        assert_eq!(
+            cycle_of_pattern(&Pattern::Blinker),
+            Cycle::new(0, 2, Position::new(0, 0))
+        );
+        assert_eq!(cycle_of_pattern(&Pattern::Pulsar).period(), 3);
+        assert_eq!(cycle_of_pattern(&Pattern::Pulsar).speed(), None);
+    }
+
+    #[test]
+    fn should_report_the_generation_a_cycle_was_entered() {
+        let world = World::from(Cells::from_iter([Cell::new(0, 0)]));
+        assert_eq!(cycle_of(world), Cycle::new(1, 1, Position::new(0, 0)));
+    }
+
+    #[test]
+    fn should_report_the_speed_of_a_spaceship() {
+        let glider = cycle_of_pattern(&Pattern::Glider);
+        assert_eq!(glider.period(), 4);
+        assert_eq!(glider.displacement().row().abs(), 1);
+        assert_eq!(glider.displacement().column().abs(), 1);
+        assert_eq!(glider.speed(), Some("c/4 diagonal".into()));
+
+        let lwss = cycle_of_pattern(&Pattern::SpaceshipLightweight);
+        assert_eq!(lwss.period(), 4);
+        assert_eq!(lwss.speed(), Some("c/2 orthogonal".into()));
+    }
+
+    #[test]
+    fn should_name_every_kind_of_speed() {
+        let speed = |period, row, column| Cycle::new(0, period, Position::new(row, column)).speed();
+        assert_eq!(speed(5, 0, -2), Some("2c/5 orthogonal".into()));
+        assert_eq!(speed(12, 3, 3), Some("c/4 diagonal".into()));
+        assert_eq!(speed(6, -1, 2), Some("(2,1)c/6".into()));
+        assert_eq!(speed(1, 1, 0), Some("c orthogonal".into()));
+        assert_eq!(speed(2, 0, 0), None);
+    }
+
+    #[test]
+    fn should_only_find_exact_repeats_in_bounded_worlds() {
+        let mut world = World::try_from(&Pattern::Glider).expect("valid pattern");
+        world
+            .with_bounds(&Bounds::new(0..=7, 0..=7))
+            .expect("bounds suit the topology");
+        world
+            .with_topology(&Topology::Torus)
+            .expect("compatible topology");
+        let cycle = cycle_of(world);
+        assert_eq!(cycle.period(), 32);
+        assert!(!cycle.is_moving());
+    }
+
+    #[test]
+    fn should_summarise_a_cycle() {
+        let cycle = Cycle::new(3, 4, Position::new(-1, 1));
+        assert_eq!(
+            cycle.to_string(),
+            "period 4 from generation 3, moving (-1, 1) at c/4 diagonal"
+        );
+        assert_eq!(
+            Cycle::new(0, 2, Position::new(0, 0)).to_string(),
+            "period 2 from generation 0"
+        );
+    }
+
+    #[test]
+    fn should_not_spot_cycles_longer_than_the_history() {
+        let world = World::try_from(&Pattern::Pulsar).expect("valid pattern");
+        let mut short = Generations::new(world.clone());
+        short.with_history(&History::new(2, 1));
+        assert!((0..20).all(|_| short.next_generation().is_some()));
+        assert_eq!(short.cycle(), None);
+
+        let mut long = Generations::new(world);
+        long.with_history(&History::new(3, 1));
+        while long.next_generation().is_some() {}
+        assert_eq!(long.cycle().map(|c| c.period()), Some(3));
+    }
+
+    #[test]
+    fn should_confirm_cycles_whatever_the_checkpoint_interval() {
+        let expected = cycle_of_pattern(&Pattern::Glider);
+        for interval in [1, 3, 1000] {
+            let world = World::try_from(&Pattern::Glider).expect("valid pattern");
+            let mut generations = Generations::new(world);
+            generations.with_history(&History::new(100, interval));
+            while generations.cycle().is_none() {
+                generations.next_generation();
+            }
+            assert_eq!(generations.cycle(), Some(&expected));
+        }
+    }
 }
@@ -0,0 +1,123 @@
+mod hashlife {
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    fn hashlife(pattern: &Pattern) -> HashLife {
+        let cells = Cells::try_from(pattern.cells_str()).expect("valid pattern");
+        let mut hashlife = HashLife::new(&Rule::default()).expect("supported rule");
+        hashlife.with_cells(&cells);
+        hashlife
+    }
+
+    fn stepped(pattern: &Pattern, generations: usize) -> World {
+        let mut world = World::try_from(pattern).expect("valid pattern");
+        (0..generations).for_each(|_| world.next_generation());
+        world
+    }
+
+    #[test]
+    fn should_not_support_b0_or_multi_state_rules() {
+        assert!(HashLife::new(&"B2/S".parse().expect("valid rule")).is_ok());
+        assert!(HashLife::new(&"B0/S8".parse().expect("valid rule")).is_err());
+        assert!(HashLife::new(&"B2/S/C3".parse().expect("valid rule")).is_err());
+        assert!(HashLife::new(&"R2,S1..1,B1..1".parse().expect("valid rule")).is_err());
+    }
+
+    #[test]
+    fn should_match_the_set_engine() {
+        for generations in [1, 2, 3, 30, 255, 256, 1000] {
+            let mut hashlife = hashlife(&Pattern::GosperGliderGun);
+            hashlife.step(generations as u64).expect("stepped");
+            assert_eq!(
+                hashlife.cells(),
+                *stepped(&Pattern::GosperGliderGun, generations).live_cells(),
+                "generation {}",
+                generations
+            );
+            assert_eq!(hashlife.generation(), generations as u64);
+        }
+    }
+
+    #[test]
+    fn should_jump_a_glider_a_million_generations() {
+        let glider = Cells::try_from(Pattern::Glider.cells_str()).expect("valid pattern");
+        let displaced = stepped(&Pattern::Glider, 4).live_cells().clone();
+        let (origin, moved) = (glider.bounds(), displaced.bounds());
+        let dr = moved.rows().start() - origin.rows().start();
+        let dc = moved.columns().start() - origin.columns().start();
+
+        let mut hashlife = hashlife(&Pattern::Glider);
+        hashlife.step(1 << 20).expect("stepped");
+        let expected = Cells::from_iter(
+            glider
+                .iter()
+                .map(|c| *c + Position::new(dr << 18, dc << 18)),
+        );
+        assert_eq!(hashlife.cells(), expected);
+        assert_eq!(hashlife.population(), 5);
+    }
+
+    #[test]
+    fn should_jump_as_far_as_generations_go() {
+        let mut pulsar = hashlife(&Pattern::Pulsar);
+        let cells = pulsar.cells();
+        pulsar.step(u64::MAX).expect("stepped");
+        assert_eq!(pulsar.cells(), cells);
+        assert_eq!(pulsar.generation(), u64::MAX);
+
+        let glider = Cells::try_from(Pattern::Glider.cells_str()).expect("valid pattern");
+        let mut hashlife = hashlife(&Pattern::Glider);
+        hashlife.step(1 << 60).expect("stepped");
+        let expected = Cells::from_iter(
+            glider
+                .iter()
+                .map(|c| *c + Position::new(-(1 << 58), 1 << 58)),
+        );
+        assert_eq!(hashlife.cells(), expected);
+    }
+
+    #[test]
+    fn should_not_outgrow_the_coordinates_of_cells() {
+        let block = Cells::from_iter(
+            [(0, 0), (0, 1), (1, 0), (1, 1)].map(|(r, c)| Cell::new(isize::MAX - 10 + r, c)),
+        );
+        let mut corner = HashLife::new(&Rule::default()).expect("supported rule");
+        corner.with_cells(&block);
+        corner.step(1).expect("stepped");
+        assert!(matches!(corner.step(32), Err(HashLifeError::Overflow)));
+        assert_eq!(corner.cells(), block);
+
+        let mut glider = hashlife(&Pattern::Glider);
+        assert!(matches!(
+            glider.step(u64::MAX),
+            Err(HashLifeError::Overflow)
+        ));
+        assert_eq!(glider.population(), 5);
+    }
+
+    #[test]
+    fn should_be_selectable_by_world() {
+        let mut sets = World::try_from(&Pattern::PentaDecathlon).expect("valid pattern");
+        let mut hashlife = sets.clone();
+        hashlife.with_algorithm(&Algorithm::HashLife);
+        for _ in 0..45 {
+            sets.next_generation();
+            hashlife.next_generation();
+            assert_eq!(hashlife, sets);
+        }
+    }
+
+    #[test]
+    fn should_fall_back_to_sets_for_bounded_worlds() {
+        let mut sets = World::try_from(&Pattern::Glider).expect("valid pattern");
+        sets.with_bounds(&Bounds::new(0..=5, 0..=5))
+            .expect("bounds suit the topology");
+        let mut hashlife = sets.clone();
+        hashlife.with_algorithm(&Algorithm::HashLife);
+        for _ in 0..20 {
+            sets.next_generation();
+            hashlife.next_generation();
+        }
+        assert_eq!(hashlife, sets);
+    }
+}
@@ -0,0 +1,99 @@
+mod larger_than_life {
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    fn rule(s: &str) -> Rule {
+        s.parse().expect("valid rule")
+    }
+
+    fn single_cell_world(rule: &Rule) -> World {
+        let mut world = World::from(Cells::from_iter([Cell::new(0, 0)]));
+        world.with_rule(rule);
+        world
+    }
+
+    #[test]
+    fn should_parse_golly_notation() {
+        let bosco = rule("R5,C0,M1,S34..58,B34..45,NM");
+        assert_eq!(bosco.to_string(), "R5,C0,M1,S34..58,B34..45,NM");
+
+        let ltl = bosco.larger_than_life().expect("larger than life rule");
+        assert_eq!(ltl.range(), 5);
+        assert!(ltl.is_middle_included());
+        assert_eq!(ltl.shape(), NeighbourhoodShape::Moore);
+    }
+
+    #[test]
+    fn should_default_optional_parameters() {
+        assert_eq!(
+            rule("r2,s1..1,b1..1").to_string(),
+            "R2,C0,M0,S1..1,B1..1,NM"
+        );
+    }
+
+    #[test]
+    fn should_not_parse_invalid_parameters() {
+        assert!("R0,S1..1,B1..1".parse::<Rule>().is_err());
+        assert!("R501,S1..1,B1..1".parse::<Rule>().is_err());
+        assert!("R2,S1..1,B0..1".parse::<Rule>().is_err());
+        assert!("R2,S3..1,B1..1".parse::<Rule>().is_err());
+        assert!("R2,B1..1".parse::<Rule>().is_err());
+        assert!("R2,S1..1,B1..1,NX".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn should_match_conway_at_range_one() {
+        let mut conway = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        let mut ltl = conway.clone();
+        ltl.with_rule(&rule("R1,C0,M0,S2..3,B3..3,NM"));
+        for _ in 0..40 {
+            conway.next_generation();
+            ltl.next_generation();
+        }
+        assert_eq!(ltl.live_cells(), conway.live_cells());
+    }
+
+    #[test]
+    fn should_count_over_each_neighbourhood_shape() {
+        let sizes = ["NM", "NN", "NC"].map(|shape| {
+            let mut world = single_cell_world(&rule(&format!("R2,C0,M0,S1..1,B1..1,{}", shape)));
+            world.next_generation();
+            world.live_cells().len()
+        });
+        assert_eq!(sizes, [24, 12, 20]);
+    }
+
+    #[test]
+    fn should_count_the_middle_cell_towards_survival() {
+        let mut with_middle = single_cell_world(&rule("R1,C0,M1,S1..1,B9..9"));
+        let mut without_middle = single_cell_world(&rule("R1,C0,M0,S1..1,B9..9"));
+        with_middle.next_generation();
+        without_middle.next_generation();
+        assert!(!with_middle.is_empty());
+        assert!(without_middle.is_empty());
+    }
+
+    #[test]
+    fn should_decay_with_more_than_two_states() {
+        let mut world = single_cell_world(&rule("R2,C4,M0,S1..1,B9..9,NN"));
+        let states = (0..3)
+            .map(|_| {
+                world.next_generation();
+                world.state(&Cell::new(0, 0))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(states, vec![2, 3, 0]);
+    }
+
+    #[test]
+    fn should_save_and_reload_a_world_losslessly() {
+        let mut world = World::try_from(&Pattern::Pulsar).expect("valid pattern");
+        world.with_rule(&rule("R2,C3,M1,S4..8,B5..7,NC"));
+        (0..10).for_each(|_| world.next_generation());
+
+        let path = std::env::temp_dir().join("life_larger_than_life_spec.rle");
+        world.save(&path).expect("saved world");
+        let reloaded = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(reloaded, world);
+    }
+}
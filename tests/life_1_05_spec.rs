@@ -0,0 +1,94 @@
+mod life_1_05 {
+    use std::path::Path;
+
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    fn glider() -> Cells {
+        Cells::from_iter([
+            Cell::new(-1, 0),
+            Cell::new(0, 1),
+            Cell::new(1, -1),
+            Cell::new(1, 0),
+            Cell::new(1, 1),
+        ])
+    }
+
+    #[test]
+    fn should_preserve_absolute_block_positions() {
+        let path = Path::new("./tests/data/glider_1_05.lif");
+        let life = Life105::try_from(path).expect("valid life 1.05");
+        assert_eq!(life.cells(), &glider());
+        assert_eq!(life.rule(), None);
+        assert_eq!(life.metadata().comments().len(), 2);
+    }
+
+    #[test]
+    fn should_combine_multiple_blocks() {
+        let life =
+            Life105::try_from("#Life 1.05\n#P 0 0\n**\n#P -10 5\n.*\n").expect("valid life 1.05");
+        assert_eq!(
+            life.cells(),
+            &Cells::from_iter([Cell::new(0, 0), Cell::new(0, 1), Cell::new(5, -9)])
+        );
+    }
+
+    #[test]
+    fn should_keep_rule() {
+        let life = Life105::try_from("#Life 1.05\n#R 23/36\n#P 0 0\n*\n").expect("valid life 1.05");
+        assert_eq!(life.rule(), Some(&"B36/S23".parse().expect("valid rule")));
+    }
+
+    #[test]
+    fn should_write_rule_in_survival_birth_order() {
+        let life = Life105::try_from("#Life 1.05\n#R 23/36\n#P 0 0\n*\n").expect("valid life 1.05");
+        assert!(life.to_string().contains("\n#R 23/36\n"));
+    }
+
+    #[test]
+    fn should_write_non_totalistic_rule_in_hensel_notation() {
+        let life =
+            Life105::try_from("#Life 1.05\n#R B3/S2-i34q\n#P 0 0\n*\n").expect("valid life 1.05");
+        assert!(life.to_string().contains("\n#R B3/S2-i34q\n"));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_invalid_rule() {
+        let error = Life105::try_from("#Life 1.05\n#R 29/3\n").expect_err("invalid life 1.05");
+        assert!(matches!(error, FormatError::InvalidRule(_)));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_invalid_position() {
+        let error = Life105::try_from("#Life 1.05\n#P 0\n*\n").expect_err("invalid life 1.05");
+        assert!(matches!(error, FormatError::InvalidLine(2)));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_unexpected_characters() {
+        let error = Life105::try_from("#Life 1.05\n#P 0 0\n*o\n").expect_err("invalid life 1.05");
+        assert!(matches!(error, FormatError::UnexpectedCharacter('o', 3)));
+    }
+
+    #[test]
+    fn should_write_positioned_block() {
+        let life = Life105::from(&glider());
+        assert_eq!(life.to_string(), "#Life 1.05\n#N\n#P -1 -1\n.*\n..*\n***\n");
+    }
+
+    #[test]
+    fn should_write_wide_patterns_as_several_blocks() {
+        let cells = Cells::from_iter([Cell::new(0, 0), Cell::new(3, 100)]);
+        let text = Life105::from(&cells).to_string();
+        assert!(text.lines().all(|l| l.len() <= 80));
+        let reloaded = Life105::try_from(text.as_str()).expect("valid life 1.05");
+        assert_eq!(reloaded.cells(), &cells);
+    }
+
+    #[test]
+    fn should_be_selected_by_world_from_header() {
+        let path = Path::new("./tests/data/glider_1_05.lif");
+        let world = World::try_from(path).expect("valid world");
+        assert_eq!(world.live_cells(), &glider());
+    }
+}
@@ -0,0 +1,58 @@
+mod life_1_06 {
+    use std::path::Path;
+
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    fn glider() -> Cells {
+        Cells::from_iter([
+            Cell::new(-1, 0),
+            Cell::new(0, 1),
+            Cell::new(1, -1),
+            Cell::new(1, 0),
+            Cell::new(1, 1),
+        ])
+    }
+
+    #[test]
+    fn should_preserve_absolute_coordinates() {
+        let path = Path::new("./tests/data/glider_1_06.lif");
+        let life = Life106::try_from(path).expect("valid life 1.06");
+        assert_eq!(life.cells(), &glider());
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_invalid_coordinates() {
+        let error = Life106::try_from("#Life 1.06\n0 0\n1 a\n").expect_err("invalid life 1.06");
+        assert!(matches!(error, FormatError::InvalidLine(3)));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_too_many_coordinates() {
+        let error = Life106::try_from("#Life 1.06\n0 0 0\n").expect_err("invalid life 1.06");
+        assert!(matches!(error, FormatError::InvalidLine(2)));
+    }
+
+    #[test]
+    fn should_write_ordered_coordinates() {
+        let life = Life106::from(&glider());
+        assert_eq!(life.to_string(), "#Life 1.06\n0 -1\n1 0\n-1 1\n0 1\n1 1\n");
+    }
+
+    #[test]
+    fn should_be_selected_by_world_from_header() {
+        let path = Path::new("./tests/data/glider_1_06.lif");
+        let world = World::try_from(path).expect("valid world");
+        assert_eq!(world.live_cells(), &glider());
+    }
+
+    #[test]
+    fn should_save_and_reload_a_world() {
+        let mut world = World::try_from(&Pattern::Glider).expect("valid pattern");
+        world.add_cells(world.live_cells().clone(), &Position::new(-20, -30));
+        let path = std::env::temp_dir().join("life_1_06_spec_gliders.lif");
+        world.save(&path).expect("saved world");
+        let reloaded = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(reloaded, world);
+    }
+}
@@ -0,0 +1,126 @@
+mod macrocell {
+    use std::path::Path;
+
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_expand_quadtree_around_the_origin() {
+        let path = Path::new("./tests/data/glider.mc");
+        let macrocell = Macrocell::try_from(path).expect("valid macrocell");
+        let expected = World::try_from(&Pattern::Glider).expect("valid pattern");
+        assert_eq!(macrocell.cells(), expected.live_cells());
+        assert_eq!(macrocell.rule(), Some(&Rule::default()));
+        assert_eq!(macrocell.metadata().comments().len(), 1);
+    }
+
+    #[test]
+    fn should_place_quadrants_relative_to_the_root() {
+        let macrocell = Macrocell::try_from("[M2]\n*$\n4 1 0 0 1\n").expect("valid macrocell");
+        assert_eq!(
+            macrocell.cells(),
+            &Cells::from_iter([Cell::new(-8, -8), Cell::new(0, 0)])
+        );
+    }
+
+    #[test]
+    fn should_not_be_creatable_without_header() {
+        let error = Macrocell::try_from(".*$\n").expect_err("invalid macrocell");
+        assert!(matches!(error, FormatError::InvalidHeader(_)));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_forward_references() {
+        let error = Macrocell::try_from("[M2]\n*$\n4 0 0 0 3\n").expect_err("invalid macrocell");
+        assert!(matches!(error, FormatError::InvalidLine(3)));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_mismatched_levels() {
+        let error = Macrocell::try_from("[M2]\n*$\n5 0 0 0 1\n").expect_err("invalid macrocell");
+        assert!(matches!(error, FormatError::InvalidLine(3)));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_oversized_leaves() {
+        let error = Macrocell::try_from("[M2]\n.........*$\n").expect_err("invalid macrocell");
+        assert!(matches!(error, FormatError::InvalidLine(2)));
+    }
+
+    #[test]
+    fn should_save_and_reload_world_rule() {
+        let mut world = World::try_from(&Pattern::Glider).expect("valid pattern");
+        world.with_rule(&"B36/S23".parse().expect("valid rule"));
+        let path = std::env::temp_dir().join("life_macrocell_spec_highlife.mc");
+        world.save(&path).expect("saved world");
+        let reloaded = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(reloaded.rule(), world.rule());
+    }
+
+    #[test]
+    fn should_write_rule_and_nodes() {
+        let world = World::try_from(&Pattern::Glider).expect("valid pattern");
+        let text = Macrocell::try_from(&world)
+            .expect("dead background")
+            .to_string();
+        let body = text.lines().skip(1).collect::<Vec<_>>();
+        assert_eq!(body, ["#R B3/S23", "***$..*$.*$", "4 0 0 0 1"]);
+    }
+
+    #[test]
+    fn should_share_identical_nodes() {
+        let block = Cells::from_iter([
+            Cell::new(0, 0),
+            Cell::new(0, 1),
+            Cell::new(1, 0),
+            Cell::new(1, 1),
+        ]);
+        let mut world = World::from(Cells::default());
+        (0..16).for_each(|i| world.add_cells(block.clone(), &Position::new(64 * i, -64 * i)));
+
+        let text = Macrocell::try_from(&world)
+            .expect("dead background")
+            .to_string();
+        let leaves = text.lines().filter(|l| l.starts_with('*')).count();
+        assert_eq!(leaves, 1);
+        let reloaded = Macrocell::try_from(text.as_str()).expect("valid macrocell");
+        assert_eq!(reloaded.cells(), world.live_cells());
+    }
+
+    #[test]
+    fn should_save_and_reload_a_world_with_distant_cells() {
+        let mut world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        world.add_cells(world.live_cells().clone(), &Position::new(-5000, 123_456));
+        let path = std::env::temp_dir().join("life_macrocell_spec_guns.mc");
+        world.save(&path).expect("saved world");
+        let reloaded = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(reloaded, world);
+    }
+
+    #[test]
+    fn should_not_write_what_it_cannot_represent() {
+        let mut multi_state = World::try_from(&Pattern::Glider).expect("valid pattern");
+        multi_state.with_rule(&"345/2/4".parse().expect("valid rule"));
+        multi_state.next_generation();
+        let mut torus = World::try_from(&Pattern::Glider).expect("valid pattern");
+        torus
+            .with_bounds(&Bounds::new(0..=7, 0..=7))
+            .expect("bounds suit the topology");
+        torus
+            .with_topology(&Topology::Torus)
+            .expect("compatible topology");
+        for world in [multi_state, torus] {
+            assert!(matches!(
+                Macrocell::try_from(&world),
+                Err(FormatError::Unrepresentable(_))
+            ));
+        }
+
+        let distant = Cells::from_iter([Cell::new(isize::MIN, 0), Cell::new(0, isize::MAX)]);
+        let path = std::env::temp_dir().join("life_macrocell_spec_distant.mc");
+        assert!(matches!(
+            Macrocell::from(&distant).save(&path),
+            Err(FormatError::Unrepresentable(_))
+        ));
+    }
+}
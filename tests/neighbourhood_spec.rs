@@ -0,0 +1,94 @@
+mod neighbourhood {
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    /// A representative arrangement of each of the 51 isotropic classes, with the centre cell
+    /// shown but ignored.
+    const CLASSES: [(&str, &str); 51] = [
+        ("0", ".../.../..."),
+        ("1c", "O../.../..."),
+        ("1e", ".O./.../..."),
+        ("2c", "O.O/.../..."),
+        ("2e", ".O./O../..."),
+        ("2a", "OO./.../..."),
+        ("2i", ".../O.O/..."),
+        ("2k", "O../..O/..."),
+        ("2n", "..O/.../O.."),
+        ("3c", "O.O/.../O.."),
+        ("3e", ".O./O.O/..."),
+        ("3a", "OO./O../..."),
+        ("3i", "OOO/.../..."),
+        ("3k", ".O./..O/O.."),
+        ("3n", "O.O/O../..."),
+        ("3j", ".OO/O../..."),
+        ("3q", ".OO/.../O.."),
+        ("3r", "O../O.O/..."),
+        ("3y", "O../..O/O.."),
+        ("4c", "O.O/.../O.O"),
+        ("4e", ".O./O.O/.O."),
+        ("4a", "OOO/O../..."),
+        ("4i", "O.O/O.O/..."),
+        ("4k", "OO./..O/O.."),
+        ("4n", "OOO/.../O.."),
+        ("4j", ".O./O.O/O.."),
+        ("4q", ".OO/..O/O.."),
+        ("4r", "OO./O.O/..."),
+        ("4y", "O.O/..O/O.."),
+        ("4t", "O../O.O/O.."),
+        ("4w", ".OO/O../O.."),
+        ("4z", "..O/O.O/O.."),
+        ("5c", ".O./O.O/.OO"),
+        ("5e", "O.O/.../OOO"),
+        ("5a", "..O/..O/OOO"),
+        ("5i", ".../O.O/OOO"),
+        ("5k", "O.O/O../.OO"),
+        ("5n", ".O./..O/OOO"),
+        ("5j", "O../..O/OOO"),
+        ("5q", "O../O.O/.OO"),
+        ("5r", ".OO/.../OOO"),
+        ("5y", ".OO/O../.OO"),
+        ("6c", ".O./O.O/OOO"),
+        ("6e", "O.O/..O/OOO"),
+        ("6a", "..O/O.O/OOO"),
+        ("6i", "OOO/.../OOO"),
+        ("6k", ".OO/O../OOO"),
+        ("6n", "OO./O.O/.OO"),
+        ("7c", ".OO/O.O/OOO"),
+        ("7e", "O.O/O.O/OOO"),
+        ("8", "OOO/O.O/OOO"),
+    ];
+
+    fn neighbourhood(picture: &str) -> Neighbourhood {
+        let rows = picture.split('/').collect::<Vec<_>>();
+        let is_live =
+            |c: &Cell| rows[(c.row() + 1) as usize].as_bytes()[(c.column() + 1) as usize] == b'O';
+        Neighbourhood::of(&Cell::new(0, 0), is_live)
+    }
+
+    #[test]
+    fn should_classify_every_letter() {
+        for (class, picture) in CLASSES {
+            assert_eq!(neighbourhood(picture).to_string(), class, "{}", picture);
+        }
+    }
+
+    #[test]
+    fn should_classify_every_symmetry_alike() {
+        for (class, picture) in CLASSES {
+            assert!(
+                neighbourhood(picture)
+                    .symmetries()
+                    .all(|n| n.to_string() == class)
+            );
+        }
+    }
+
+    #[test]
+    fn should_parse_rules_for_every_letter() {
+        for (class, _) in CLASSES {
+            let rule = format!("B{}/S", class).parse::<Rule>().expect("valid rule");
+            assert_eq!(rule.to_string(), format!("B{}/S", class));
+            assert!(Neighbourhood::all().all(|n| rule.is_born_with(n) == (n.to_string() == class)));
+        }
+    }
+}
@@ -0,0 +1,78 @@
+mod objects {
+    use life::prelude::{Cell, Cells, Connectivity, Position, World};
+    use pretty_assertions::assert_eq;
+
+    fn block() -> Cells {
+        Cells::try_from("**\n**").expect("valid cells")
+    }
+
+    #[test]
+    fn should_return_each_object_from_the_origin_with_its_offset() {
+        let mut cells = block().translate(&Position::new(10, -5));
+        cells.extend(block().translate(&Position::new(-3, 7)).iter().copied());
+
+        let objects = cells.objects(&Connectivity::WithinTwo);
+        assert_eq!(
+            objects,
+            vec![
+                (block(), Position::new(-3, 7)),
+                (block(), Position::new(10, -5))
+            ]
+        );
+    }
+
+    #[test]
+    fn should_connect_neighbours_only_when_asked() {
+        let cells = Cells::try_from("**.*\n**..").expect("valid cells");
+        let neighbours = cells.objects(&Connectivity::Neighbours);
+        let within_two = cells.objects(&Connectivity::WithinTwo);
+        assert_eq!(neighbours.len(), 2);
+        assert_eq!(
+            neighbours[1],
+            (Cells::from_iter([Cell::new(0, 0)]), Position::new(0, 3))
+        );
+        assert_eq!(within_two, vec![(cells, Position::new(0, 0))]);
+    }
+
+    #[test]
+    fn should_return_nothing_when_empty() {
+        assert!(
+            Cells::default()
+                .objects(&Connectivity::WithinTwo)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn should_separate_pseudo_still_lifes() {
+        let bi_block = Cells::try_from("**.**\n**.**").expect("valid cells");
+        let world = World::from(bi_block.clone());
+        assert_eq!(bi_block.objects(&Connectivity::WithinTwo).len(), 1);
+        assert_eq!(
+            world.objects(&Connectivity::WithinTwo),
+            vec![
+                (block(), Position::new(0, 0)),
+                (block(), Position::new(0, 3))
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_separate_strict_still_lifes() {
+        let aircraft_carrier = Cells::try_from("**..\n*..*\n..**").expect("valid cells");
+        let world = World::from(aircraft_carrier.clone());
+        assert_eq!(aircraft_carrier.objects(&Connectivity::Neighbours).len(), 2);
+        assert_eq!(
+            world.objects(&Connectivity::WithinTwo),
+            vec![(aircraft_carrier, Position::new(0, 0))]
+        );
+    }
+
+    #[test]
+    fn should_not_separate_oscillators_from_still_lifes() {
+        let block_and_blinker = Cells::try_from("**.*\n**.*\n...*").expect("valid cells");
+        let world = World::from(block_and_blinker.clone());
+        assert_eq!(world.objects(&Connectivity::WithinTwo).len(), 1);
+        assert_eq!(world.objects(&Connectivity::Neighbours).len(), 2);
+    }
+}
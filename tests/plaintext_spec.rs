@@ -0,0 +1,88 @@
+mod plaintext {
+    use std::path::Path;
+
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    fn glider() -> Cells {
+        Cells::try_from(
+            r#"
+. * .
+. . *
+* * *
+"#,
+        )
+        .expect("valid cells")
+    }
+
+    #[test]
+    fn should_be_creatable_from_ragged_lines() {
+        let plaintext = Plaintext::try_from(".O\n..O\nOOO").expect("valid plaintext");
+        assert_eq!(plaintext.cells(), &glider());
+    }
+
+    #[test]
+    fn should_treat_blank_lines_as_dead_rows() {
+        let plaintext = Plaintext::try_from("O\n\nO").expect("valid plaintext");
+        assert_eq!(
+            plaintext.cells(),
+            &Cells::from_iter([Cell::new(0, 0), Cell::new(2, 0)])
+        );
+    }
+
+    #[test]
+    fn should_keep_name_and_description() {
+        let path = Path::new("./tests/data/glider.cells");
+        let plaintext = Plaintext::try_from(path).expect("valid plaintext");
+        let metadata = plaintext.metadata();
+        assert_eq!(plaintext.cells(), &glider());
+        assert_eq!(metadata.name(), Some("Glider"));
+        assert_eq!(metadata.author(), Some("Richard K. Guy"));
+        assert_eq!(
+            metadata.comments(),
+            [
+                "The smallest, most common, and first discovered spaceship.".to_string(),
+                "".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_unexpected_characters() {
+        let error = Plaintext::try_from("!Name: Bad\n.O\nx.").expect_err("invalid plaintext");
+        assert!(matches!(error, FormatError::UnexpectedCharacter('x', 3)));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_missing_file() {
+        let path = Path::new("./tests/data/no_world_file.cells");
+        let error = Plaintext::try_from(path).expect_err("invalid plaintext");
+        assert!(matches!(error, FormatError::FileError(_)));
+    }
+
+    #[test]
+    fn should_write_metadata_and_rows() {
+        let path = Path::new("./tests/data/glider.cells");
+        let plaintext = Plaintext::try_from(path).expect("valid plaintext");
+        assert_eq!(
+            plaintext.to_string(),
+            "!Name: Glider\n!Author: Richard K. Guy\n!The smallest, most common, and first discovered spaceship.\n!\n.O.\n..O\nOOO\n"
+        );
+    }
+
+    #[test]
+    fn should_be_selected_by_world_for_cells_files() {
+        let path = Path::new("./tests/data/glider.cells");
+        let world = World::try_from(path).expect("valid world");
+        assert_eq!(world.live_cells(), &glider());
+    }
+
+    #[test]
+    fn should_save_and_reload_a_world() {
+        let world = World::try_from(&Pattern::Pulsar).expect("valid pattern");
+        let path = std::env::temp_dir().join("life_plaintext_spec_pulsar.cells");
+        world.save(&path).expect("saved world");
+        let reloaded = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(reloaded, world);
+    }
+}
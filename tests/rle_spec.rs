@@ -0,0 +1,305 @@
+mod rle {
+    use std::path::Path;
+
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_be_creatable_from_header_and_runs() {
+        let rle = Rle::try_from("x = 3, y = 3, rule = B3/S23\nbob$2bo$3o!").expect("valid rle");
+        let expected = Cells::try_from(
+            r#"
+. * .
+. . *
+* * *
+"#,
+        )
+        .expect("valid cells");
+        assert_eq!(rle.cells(), &expected);
+        assert_eq!(rle.rule(), Some(&Rule::default()));
+    }
+
+    #[test]
+    fn should_be_creatable_without_a_header() {
+        let rle = Rle::try_from("2o$2o!").expect("valid rle");
+        let expected = Cells::try_from("* *\n* *").expect("valid cells");
+        assert_eq!(rle.cells(), &expected);
+        assert_eq!(rle.rule(), None);
+    }
+
+    #[test]
+    fn should_accept_multi_digit_run_counts() {
+        let rle = Rle::try_from("x = 12, y = 1\n11bo!").expect("valid rle");
+        assert_eq!(rle.cells(), &Cells::from_iter([Cell::new(0, 11)]));
+    }
+
+    #[test]
+    fn should_accept_runs_of_blank_rows() {
+        let rle = Rle::try_from("x = 1, y = 4\no3$o!").expect("valid rle");
+        assert_eq!(
+            rle.cells(),
+            &Cells::from_iter([Cell::new(0, 0), Cell::new(3, 0)])
+        );
+    }
+
+    #[test]
+    fn should_accept_runs_wrapped_across_lines() {
+        let rle = Rle::try_from("x = 14, y = 2\n1\n2bo$\n2o\n!").expect("valid rle");
+        assert_eq!(
+            rle.cells(),
+            &Cells::from_iter([Cell::new(0, 12), Cell::new(1, 0), Cell::new(1, 1)])
+        );
+    }
+
+    #[test]
+    fn should_ignore_content_after_the_terminator() {
+        let rle = Rle::try_from("x = 1, y = 1\no!\nthis is ignored").expect("valid rle");
+        assert_eq!(rle.cells(), &Cells::from_iter([Cell::new(0, 0)]));
+    }
+
+    #[test]
+    fn should_parse_header_rule() {
+        let rle = Rle::try_from("x = 1, y = 1, rule = b36s23\no!").expect("valid rle");
+        assert_eq!(rle.rule().map(Rule::to_string), Some("B36/S23".into()));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_invalid_rule() {
+        let error = Rle::try_from("x = 1, y = 1, rule = B3/X23\no!").expect_err("invalid rle");
+        assert!(matches!(error, FormatError::InvalidRule(_)));
+    }
+
+    #[test]
+    fn should_be_selected_by_world_with_header_rule() {
+        let path = std::env::temp_dir().join("life_rle_spec_highlife.rle");
+        std::fs::write(&path, "x = 3, y = 1, rule = B36/S23\n3o!").expect("written rle");
+        let world = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(world.rule().to_string(), "B36/S23");
+    }
+
+    #[test]
+    fn should_keep_name_author_and_comments() {
+        let path = Path::new("./tests/data/glider.rle");
+        let rle = Rle::try_from(path).expect("valid rle");
+        let metadata = rle.metadata();
+        assert_eq!(metadata.name(), Some("Glider"));
+        assert_eq!(metadata.author(), Some("Richard K. Guy"));
+        assert_eq!(metadata.comments().len(), 2);
+    }
+
+    #[test]
+    fn should_offset_cells_by_extended_position() {
+        let rle = Rle::try_from("#CXRLE Pos=-2,5\nx = 2, y = 1\nbo!").expect("valid rle");
+        assert_eq!(rle.cells(), &Cells::from_iter([Cell::new(5, -1)]));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_invalid_header() {
+        let error = Rle::try_from("x = three, y = 3\nbo!").expect_err("invalid rle");
+        assert!(matches!(error, FormatError::InvalidHeader(_)));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_unexpected_characters() {
+        let path = Path::new("./tests/data/invalid_world.rle");
+        let error = Rle::try_from(path).expect_err("invalid rle");
+        assert!(matches!(error, FormatError::UnexpectedCharacter('z', 2)));
+    }
+
+    #[test]
+    fn should_read_multi_state_letters() {
+        let rle = Rle::try_from("x = 4, y = 1, rule = B2/S345/C30\n.ABpA!").expect("valid rle");
+        assert_eq!(rle.cells(), &Cells::from_iter([Cell::new(0, 1)]));
+        assert_eq!(rle.decaying_cells().state(&Cell::new(0, 2)), 2);
+        assert_eq!(rle.decaying_cells().state(&Cell::new(0, 3)), 25);
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_dangling_state_prefix() {
+        assert!(Rle::try_from("x = 1, y = 1\np.!").is_err());
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_overlong_runs() {
+        let error = Rle::try_from("x = 1, y = 1\n99999999999999999999o!").expect_err("invalid rle");
+        assert!(matches!(error, FormatError::RunTooLong(2)));
+    }
+
+    #[test]
+    fn should_not_be_creatable_from_missing_file() {
+        let path = Path::new("./tests/data/no_world_file.rle");
+        let error = Rle::try_from(path).expect_err("invalid rle");
+        assert!(matches!(error, FormatError::FileError(_)));
+    }
+
+    #[test]
+    fn should_be_selected_by_world_for_rle_files() {
+        let path = Path::new("./tests/data/glider.rle");
+        let world = World::try_from(path).expect("valid world");
+        let expected = Cells::try_from(
+            r#"
+. * .
+. . *
+* * *
+"#,
+        )
+        .expect("valid cells");
+        assert_eq!(world.live_cells(), &expected);
+    }
+}
+
+mod rle_writer {
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_write_header_and_runs() {
+        let rle = Rle::try_from("x = 3, y = 3, rule = B3/S23\nbob$2bo$3o!").expect("valid rle");
+        assert_eq!(rle.to_string(), "x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!\n");
+    }
+
+    #[test]
+    fn should_write_runs_of_blank_rows() {
+        let cells = Cells::from_iter([Cell::new(0, 0), Cell::new(3, 2)]);
+        assert_eq!(
+            Rle::from(&cells).to_string(),
+            "x = 3, y = 4, rule = B3/S23\no3$2bo!\n"
+        );
+    }
+
+    #[test]
+    fn should_write_empty_cells() {
+        let cells = Cells::default();
+        assert_eq!(
+            Rle::from(&cells).to_string(),
+            "x = 0, y = 0, rule = B3/S23\n!\n"
+        );
+    }
+
+    #[test]
+    fn should_write_metadata() {
+        let text =
+            "#N Glider\n#O Richard K. Guy\n#C A comment\nx = 3, y = 3, rule = B3/S23\nbo$2bo$3o!\n";
+        let rle = Rle::try_from(text).expect("valid rle");
+        assert_eq!(rle.to_string(), text);
+    }
+
+    #[test]
+    fn should_write_position_when_not_at_origin() {
+        let cells = Cells::from_iter([Cell::new(-3, 5)]);
+        let text = Rle::from(&cells).to_string();
+        assert_eq!(text, "#CXRLE Pos=5,-3\nx = 1, y = 1, rule = B3/S23\no!\n");
+        assert_eq!(
+            Rle::try_from(text.as_str()).expect("valid rle").cells(),
+            &cells
+        );
+    }
+
+    #[test]
+    fn should_wrap_lines_at_seventy_columns() {
+        let cells = Cells::from_iter((0..400).step_by(3).map(|c| Cell::new(c % 7, c)));
+        let text = Rle::from(&cells).to_string();
+        assert!(text.lines().all(|l| l.len() <= 70));
+        assert_eq!(
+            Rle::try_from(text.as_str()).expect("valid rle").cells(),
+            &cells
+        );
+    }
+
+    #[test]
+    fn should_write_world_bounds_centred_on_the_origin() {
+        let mut world = World::try_from(&Pattern::Glider).expect("valid pattern");
+        world
+            .with_bounds(&Bounds::new(0..=9, 0..=19))
+            .expect("bounds suit the topology");
+        let rle = Rle::try_from(&world).expect("dead background");
+        assert_eq!(rle.bounds(), Some(&Bounds::new(0..=9, 0..=19)));
+        assert_eq!(
+            rle.to_string(),
+            "#CXRLE Pos=-10,-5 Origin=0,0\nx = 3, y = 3, rule = B3/S23:P20,10\n3o$2bo$bo!\n"
+        );
+    }
+
+    #[test]
+    fn should_write_world_rule() {
+        let mut world = World::try_from(&Pattern::Blinker).expect("valid pattern");
+        world.with_rule(&"B2/S".parse().expect("valid rule"));
+        assert_eq!(
+            Rle::try_from(&world).expect("dead background").to_string(),
+            "x = 3, y = 1, rule = B2/S\n3o!\n"
+        );
+    }
+
+    #[test]
+    fn should_write_multi_state_letters() {
+        let mut world = World::from(Cells::try_from("* *").expect("valid cells"));
+        world.with_rule(&"B2/S/C3".parse().expect("valid rule"));
+        world.next_generation();
+        assert_eq!(
+            Rle::try_from(&world).expect("dead background").to_string(),
+            "#CXRLE Pos=0,-1\nx = 2, y = 3, rule = B2/S/C3\n2A$2B$2A!\n"
+        );
+    }
+
+    #[test]
+    fn should_save_and_reload_a_multi_state_world_losslessly() {
+        let mut world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        world.with_rule(&"345/2/4".parse().expect("valid rule"));
+        (0..20).for_each(|_| world.next_generation());
+
+        let path = std::env::temp_dir().join("life_rle_spec_star_wars.rle");
+        world.save(&path).expect("saved world");
+        let reloaded = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(reloaded, world);
+    }
+
+    #[test]
+    fn should_save_and_reload_a_world_losslessly() {
+        let mut world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        world
+            .with_bounds(&Bounds::new(-20..=19, -25..=24))
+            .expect("bounds suit the topology");
+        (0..50).for_each(|_| world.next_generation());
+
+        let path = std::env::temp_dir().join("life_rle_spec_gosper_glider_gun.rle");
+        world.save(&path).expect("saved world");
+        let reloaded = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(reloaded, world);
+    }
+
+    #[test]
+    fn should_save_and_reload_a_world_with_off_centre_bounds_losslessly() {
+        let mut world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        world
+            .with_bounds(&Bounds::new(0..=39, 5..=54))
+            .expect("bounds suit the topology");
+        (0..50).for_each(|_| world.next_generation());
+
+        let path = std::env::temp_dir().join("life_rle_spec_off_centre.rle");
+        world.save(&path).expect("saved world");
+        let reloaded = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(reloaded.bounds(), &Bounds::new(0..=39, 5..=54));
+        assert_eq!(reloaded, world);
+    }
+
+    #[test]
+    fn should_not_save_a_world_whose_background_is_live() {
+        let mut world = World::try_from(&Pattern::Glider).expect("valid pattern");
+        world.with_rule(&"B0/S".parse().expect("valid rule"));
+        world.next_generation();
+        assert!(world.is_background_live());
+
+        assert!(matches!(
+            Rle::try_from(&world),
+            Err(FormatError::LiveBackground)
+        ));
+        for extension in ["rle", "cells", "lif", "mc"] {
+            let path = std::env::temp_dir().join(format!("life_rle_spec_live.{}", extension));
+            let error = world.save(&path).expect_err("live background");
+            assert!(matches!(
+                error,
+                WorldError::BadFormat(FormatError::LiveBackground)
+            ));
+        }
+    }
+}
@@ -0,0 +1,318 @@
+mod rule {
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    fn rule(s: &str) -> Rule {
+        s.parse().expect("valid rule")
+    }
+
+    #[test]
+    fn should_default_to_conway() {
+        assert_eq!(Rule::default().to_string(), "B3/S23");
+    }
+
+    #[test]
+    fn should_parse_birth_survival_notation() {
+        assert_eq!(rule("B36/S23").to_string(), "B36/S23");
+    }
+
+    #[test]
+    fn should_parse_survival_birth_notation() {
+        assert_eq!(rule("23/36"), rule("B36/S23"));
+    }
+
+    #[test]
+    fn should_parse_lower_case_notation_without_separator() {
+        assert_eq!(rule("b3s23"), Rule::default());
+    }
+
+    #[test]
+    fn should_parse_sections_in_either_order() {
+        assert_eq!(rule("S23/B3"), Rule::default());
+    }
+
+    #[test]
+    fn should_parse_empty_sections() {
+        let seeds = rule("B2/S");
+        assert!(seeds.is_born(2));
+        assert!((0..=8).all(|n| !seeds.survives(n)));
+    }
+
+    #[test]
+    fn should_not_parse_invalid_counts() {
+        assert!("B39/S23".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn should_not_parse_repeated_sections() {
+        assert!("B3/S23/B6".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn should_not_parse_missing_sections() {
+        assert!("B3".parse::<Rule>().is_err());
+        assert!("23".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn should_not_parse_unknown_characters() {
+        assert!("B3/S23x".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn should_parse_generations_notation() {
+        let brians_brain = rule("B2/S/C3");
+        assert_eq!(brians_brain.states(), 3);
+        assert_eq!(brians_brain.to_string(), "B2/S/C3");
+        assert_eq!(rule("/2/3"), brians_brain);
+        assert_eq!(rule("345/2/4").to_string(), "B2/S345/C4");
+    }
+
+    #[test]
+    fn should_not_parse_generations_with_b0() {
+        assert!("B0/S/C3".parse::<Rule>().is_err());
+        assert!("B2/S/C1".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn should_decay_under_brians_brain() {
+        let mut world = World::from(Cells::try_from("* *").expect("valid cells"));
+        world.with_rule(&rule("B2/S/C3"));
+        world.next_generation();
+        assert_eq!(world.state(&Cell::new(0, 0)), 2);
+        assert_eq!(world.state(&Cell::new(-1, 0)), 1);
+        assert_eq!(world.to_string(), "* *\n+ +\n* *");
+    }
+
+    #[test]
+    fn should_pass_through_every_decaying_state_under_star_wars() {
+        let mut world = World::from(Cells::from_iter([Cell::new(0, 0)]));
+        world.with_rule(&rule("345/2/4"));
+        let states = (0..4)
+            .map(|_| {
+                world.next_generation();
+                world.state(&Cell::new(0, 0))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(states, vec![2, 3, 0, 0]);
+        assert!(world.is_empty());
+    }
+
+    #[test]
+    fn should_render_decaying_states() {
+        let mut world = World::from(Cells::from_iter([Cell::new(0, 0)]));
+        world.with_rule(&rule("345/2/5"));
+        let rendered = (0..3)
+            .map(|_| {
+                world.next_generation();
+                world.to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rendered, vec!["+", "-", "."]);
+    }
+
+    #[test]
+    fn should_parse_hensel_notation() {
+        let rule = rule("B2-a/S12");
+        assert_eq!(rule.to_string(), "B2-a/S12");
+        assert!(!rule.is_totalistic());
+        assert!(rule.is_born_with(Neighbourhood::new(0b101)));
+        assert!(!rule.is_born_with(Neighbourhood::new(0b11)));
+    }
+
+    #[test]
+    fn should_write_the_shorter_list_of_letters() {
+        assert_eq!(rule("B3ceaikn/S2-ce").to_string(), "B3-jqry/S2-ce");
+        assert_eq!(rule("B3ceaiknjqry/S").to_string(), "B3/S");
+    }
+
+    #[test]
+    fn should_parse_named_rules() {
+        assert_eq!(rule("tlife"), rule("B3/S2-i34q"));
+        assert_eq!(rule("HighLife"), rule("B36/S23"));
+    }
+
+    #[test]
+    fn should_not_parse_invalid_letters() {
+        assert!("B1a/S".parse::<Rule>().is_err());
+        assert!("B2-/S".parse::<Rule>().is_err());
+        assert!("B3/S8c".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn should_distinguish_arrangements_under_tlife() {
+        let mut world = World::from(Cells::try_from("* * *").expect("valid cells"));
+        world.with_rule(&rule("tlife"));
+        world.next_generation();
+        assert!(!world.live_cells().contains(&Cell::new(0, 1)));
+
+        let mut world = World::from(Cells::try_from("* *\n* .").expect("valid cells"));
+        world.with_rule(&rule("tlife"));
+        world.next_generation();
+        assert!(world.live_cells().contains(&Cell::new(0, 0)));
+    }
+
+    #[test]
+    fn should_be_born_with_six_neighbours_under_highlife() {
+        let initial_cells = Cells::try_from(
+            r#"
+* * .
+* . *
+* * .
+"#,
+        )
+        .expect("valid cells");
+        let mut conway = World::from(initial_cells.clone());
+        let mut highlife = World::from(initial_cells);
+        highlife.with_rule(&rule("B36/S23"));
+
+        conway.next_generation();
+        highlife.next_generation();
+
+        assert!(!conway.live_cells().contains(&Cell::new(1, 1)));
+        assert!(highlife.live_cells().contains(&Cell::new(1, 1)));
+    }
+
+    #[test]
+    fn should_not_survive_under_seeds() {
+        let initial_cells = Cells::try_from("* *").expect("valid cells");
+        let mut world = World::from(initial_cells);
+        world.with_rule(&rule("B2/S"));
+        world.next_generation();
+        let expected_cells = Cells::from_iter([
+            Cell::new(-1, 0),
+            Cell::new(-1, 1),
+            Cell::new(1, 0),
+            Cell::new(1, 1),
+        ]);
+        assert_eq!(world.live_cells(), &expected_cells);
+    }
+
+    #[test]
+    fn should_bring_the_unbounded_background_to_life_under_b0() {
+        let mut world = World::from(Cells::from_iter([Cell::new(0, 0)]));
+        world.with_rule(&rule("B0123478/S34678"));
+        world.with_viewport(&Bounds::new(-1..=1, -1..=1));
+
+        world.next_generation();
+        assert!(world.is_background_live());
+        assert_eq!(world.live_cells(), &Cells::from_iter([Cell::new(0, 0)]));
+        assert_eq!(world.to_string(), "* * *\n*   *\n* * *");
+
+        world.next_generation();
+        assert!(world.is_background_live());
+        assert!(world.live_cells().is_empty());
+        assert!(!world.is_empty());
+    }
+
+    #[test]
+    fn should_strobe_the_unbounded_background_under_b0_without_s8() {
+        let mut world = World::from(Cells::default());
+        world.with_rule(&rule("B0/S"));
+        world.with_viewport(&Bounds::new(0..=0, 0..=1));
+
+        world.next_generation();
+        assert!(world.is_background_live());
+        assert_eq!(world.to_string(), "* *");
+
+        world.next_generation();
+        assert!(!world.is_background_live());
+        assert_eq!(world.to_string(), "   ");
+    }
+
+    /// A direct simulation of a window onto the plane, beyond which every cell takes the
+    /// background state.
+    fn reference(rule: &Rule, initial: &Cells, window: isize, generations: usize) -> String {
+        let range = -window..=window;
+        let mut background = false;
+        let mut grid = range
+            .clone()
+            .map(|r| {
+                range
+                    .clone()
+                    .map(|c| initial.contains(&Cell::new(r, c)))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        for _ in 0..generations {
+            let state = |r: isize, c: isize| {
+                let (r, c) = (r + window, c + window);
+                grid.get(r as usize)
+                    .and_then(|row| row.get(c as usize))
+                    .copied()
+                    .unwrap_or(background)
+            };
+            let next = |is_live: bool, count: usize| {
+                if is_live {
+                    rule.survives(count)
+                } else {
+                    rule.is_born(count)
+                }
+            };
+            grid = range
+                .clone()
+                .map(|r| {
+                    range
+                        .clone()
+                        .map(|c| {
+                            let count = Cell::new(r, c)
+                                .neighbours()
+                                .filter(|n| state(n.row(), n.column()))
+                                .count();
+                            next(state(r, c), count)
+                        })
+                        .collect()
+                })
+                .collect();
+            background = next(background, if background { 8 } else { 0 });
+        }
+
+        grid.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|is_live| if *is_live { "*" } else { " " })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn should_match_a_direct_simulation_under_b0() {
+        let initial = World::try_from(&Pattern::Glider)
+            .expect("valid pattern")
+            .live_cells()
+            .clone();
+        for notation in ["B0123478/S34678", "B012345678/S", "B0/S8", "B03/S23"] {
+            let rule = rule(notation);
+            let mut world = World::from(initial.clone());
+            world.with_rule(&rule);
+            world.with_viewport(&Bounds::new(-12..=12, -12..=12));
+
+            for generation in 1..=6 {
+                world.next_generation();
+                assert_eq!(
+                    world.to_string(),
+                    reference(&rule, &initial, 12, generation),
+                    "{} generation {}",
+                    notation,
+                    generation
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn should_fill_bounded_worlds_under_b0() {
+        let mut world = World::from(Cells::default());
+        world.with_rule(&rule("B0/S"));
+        world
+            .with_bounds(&Bounds::new(0..=2, 0..=2))
+            .expect("bounds suit the topology");
+        world.next_generation();
+        assert!(!world.is_background_live());
+        assert_eq!(world.live_cells().len(), 9);
+    }
+}
@@ -0,0 +1,91 @@
+mod search {
+    use life::prelude::{Cell, Cells, Orientation, Pattern, Position, Search, World};
+    use pretty_assertions::assert_eq;
+
+    fn glider() -> Cells {
+        Cells::try_from(Pattern::Glider.cells_str()).expect("valid pattern")
+    }
+
+    /// Whether the pattern, in the orientation found, is in the cells at the position found.
+    fn is_found(
+        cells: &Cells,
+        pattern: &Cells,
+        (position, orientation): &(Position, Orientation),
+    ) -> bool {
+        let placed = pattern
+            .clone()
+            .transform(orientation)
+            .normalise()
+            .translate(position);
+        placed.iter().all(|cell| cells.contains(cell))
+    }
+
+    #[test]
+    fn should_find_the_pattern_in_every_orientation() {
+        let mut cells = Cells::default();
+        let positions = Orientation::ALL.map(|orientation| {
+            let position = Position::new(0, 10 * orientation as isize);
+            let placed = glider().transform(&orientation).normalise();
+            cells.extend(placed.translate(&position).iter().copied());
+            position
+        });
+
+        let found = cells.find(&Search::new(&glider()));
+        assert_eq!(found.iter().map(|(p, _)| *p).collect::<Vec<_>>(), positions);
+        assert!(found.iter().all(|hit| is_found(&cells, &glider(), hit)));
+    }
+
+    #[test]
+    fn should_report_symmetric_patterns_once() {
+        let block = Cells::try_from("**\n**").expect("valid cells");
+        let found = block.find(&Search::new(&block));
+        assert_eq!(found, vec![(Position::new(0, 0), Orientation::Identity)]);
+    }
+
+    #[test]
+    fn should_require_the_rest_of_the_bounds_to_be_dead() {
+        let block = Cells::try_from("**\n**").expect("valid cells");
+        let l_tromino = Cells::try_from("**\n*.").expect("valid cells");
+        assert!(block.find(&Search::new(&l_tromino)).is_empty());
+    }
+
+    #[test]
+    fn should_exclude_matches_within_larger_structures_with_a_dead_border() {
+        let row = Cells::try_from("*****").expect("valid cells");
+        let blinker = Cells::try_from("***").expect("valid cells");
+        let mut search = Search::new(&blinker);
+        assert_eq!(row.find(&search).len(), 3);
+
+        search.with_dead_border(true);
+        assert!(search.dead_border());
+        assert!(row.find(&search).is_empty());
+
+        let mut block_and_cell = Cells::try_from("**\n**").expect("valid cells");
+        let block = block_and_cell.clone();
+        block_and_cell.insert(Cell::new(2, 2));
+        assert_eq!(block_and_cell.find(&Search::new(&block)).len(), 1);
+        let mut search = Search::new(&block);
+        search.with_dead_border(true);
+        assert!(block_and_cell.find(&search).is_empty());
+    }
+
+    #[test]
+    fn should_find_nothing_for_an_empty_pattern() {
+        assert!(glider().find(&Search::new(&Cells::default())).is_empty());
+    }
+
+    #[test]
+    fn should_find_the_gliders_from_a_gun() {
+        let mut world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        world.step_n(121);
+        let mut search = Search::new(&glider());
+        search.with_dead_border(true);
+        let found = world.find(&search);
+        assert_eq!(found.len(), 4);
+        assert!(
+            found
+                .iter()
+                .all(|hit| is_found(world.live_cells(), &glider(), hit))
+        );
+    }
+}
@@ -0,0 +1,114 @@
+mod tiles {
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+    use rand::{RngExt, SeedableRng, rngs::StdRng};
+
+    /// A soup straddling the tiles around the origin.
+    fn soup(seed: u64) -> Cells {
+        let mut rng = StdRng::seed_from_u64(seed);
+        Cells::from_iter(
+            (-40..80)
+                .flat_map(|r| (-70..30).map(move |c| Cell::new(r, c)))
+                .filter(|_| rng.random::<f32>() < 0.35),
+        )
+    }
+
+    fn worlds(cells: Cells, rule: &Rule, algorithm: &Algorithm) -> (World, World) {
+        let mut sets = World::from(cells);
+        sets.with_rule(rule);
+        sets.with_algorithm(&Algorithm::Sets);
+        let mut other = sets.clone();
+        other.with_algorithm(algorithm);
+        (sets, other)
+    }
+
+    #[test]
+    fn should_not_support_b0_multi_state_or_non_totalistic_rules() {
+        assert!(Tiles::new(&"B36/S23".parse().expect("valid rule")).is_ok());
+        assert!(Tiles::new(&"B0/S8".parse().expect("valid rule")).is_err());
+        assert!(Tiles::new(&"B2/S/C3".parse().expect("valid rule")).is_err());
+        assert!(Tiles::new(&"B2a/S12".parse().expect("valid rule")).is_err());
+        assert!(Tiles::new(&"R2,S1..1,B1..1".parse().expect("valid rule")).is_err());
+    }
+
+    #[test]
+    fn should_match_the_set_engine() {
+        for rule in ["B3/S23", "B36/S23", "B2/S"] {
+            let rule = rule.parse().expect("valid rule");
+            let (mut sets, mut tiles) = worlds(soup(1), &rule, &Algorithm::Tiles);
+            for generation in 0..30 {
+                sets.next_generation();
+                tiles.next_generation();
+                assert_eq!(tiles, sets, "{} generation {}", rule, generation);
+            }
+        }
+    }
+
+    #[test]
+    fn should_step_many_generations_at_once() {
+        let cells = soup(2);
+        let mut tiles = Tiles::new(&Rule::default()).expect("supported rule");
+        tiles.with_cells(&cells);
+        tiles.step(100);
+
+        let (mut sets, _) = worlds(cells, &Rule::default(), &Algorithm::Sets);
+        (0..100).for_each(|_| sets.next_generation());
+        assert_eq!(tiles.cells(), *sets.live_cells());
+        assert_eq!(tiles.population(), sets.live_cells().len() as u64);
+        assert_eq!(tiles.generation(), 100);
+    }
+
+    #[test]
+    fn should_carry_a_glider_across_tiles() {
+        let glider = Cells::try_from(Pattern::Glider.cells_str()).expect("valid pattern");
+        let mut tiles = Tiles::new(&Rule::default()).expect("supported rule");
+        tiles.with_cells(&glider);
+        tiles.step(4 * 200);
+        assert_eq!(tiles.cells().bounds().height(), glider.bounds().height());
+        assert_eq!(tiles.population(), 5);
+        assert!(tiles.tile_count() <= 4);
+    }
+
+    #[test]
+    fn should_be_chosen_automatically_for_dense_soups() {
+        let (mut sets, mut auto) = worlds(soup(3), &Rule::default(), &Algorithm::Auto);
+        for _ in 0..20 {
+            sets.next_generation();
+            auto.next_generation();
+        }
+        assert_eq!(auto, sets);
+    }
+
+    #[test]
+    fn should_lose_cells_leaving_a_bounded_plane() {
+        let (mut sets, mut tiles) = worlds(soup(4), &Rule::default(), &Algorithm::Tiles);
+        sets.with_bounds(&Bounds::new(-50..=50, -30..=30))
+            .expect("bounds suit the topology");
+        tiles
+            .with_bounds(&Bounds::new(-50..=50, -30..=30))
+            .expect("bounds suit the topology");
+        for _ in 0..30 {
+            sets.next_generation();
+            tiles.next_generation();
+        }
+        assert_eq!(tiles, sets);
+    }
+
+    #[test]
+    fn should_fall_back_to_sets_for_joined_edges() {
+        let (mut sets, mut tiles) = worlds(soup(5), &Rule::default(), &Algorithm::Tiles);
+        for world in [&mut sets, &mut tiles] {
+            world
+                .with_bounds(&Bounds::new(-20..=20, -20..=20))
+                .expect("bounds suit the topology");
+            world
+                .with_topology(&Topology::Torus)
+                .expect("compatible topology");
+        }
+        for _ in 0..30 {
+            sets.next_generation();
+            tiles.next_generation();
+        }
+        assert_eq!(tiles, sets);
+    }
+}
@@ -0,0 +1,199 @@
+mod topology {
+    use life::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    fn world(pattern: &Pattern, bounds: Bounds, topology: Topology) -> World {
+        let mut world = World::try_from(pattern).expect("valid pattern");
+        world
+            .with_bounds(&bounds)
+            .expect("bounds suit the topology");
+        world.with_topology(&topology).expect("compatible topology");
+        world
+    }
+
+    #[test]
+    fn should_parse_topology_names() {
+        assert_eq!("plane".parse::<Topology>().ok(), Some(Topology::Plane));
+        assert_eq!("Torus".parse::<Topology>().ok(), Some(Topology::Torus));
+        assert!("mobius".parse::<Topology>().is_err());
+    }
+
+    #[test]
+    fn should_return_a_glider_to_its_start_on_a_torus() {
+        let mut world = world(&Pattern::Glider, Bounds::new(0..=9, 0..=9), Topology::Torus);
+        let initial = world.live_cells().clone();
+        for generation in 1..=40 {
+            world.next_generation();
+            assert_eq!(world.live_cells().len(), 5, "generation {}", generation);
+        }
+        assert_eq!(world.live_cells(), &initial);
+    }
+
+    #[test]
+    fn should_lose_a_glider_at_the_edge_of_a_plane() {
+        let mut world = world(&Pattern::Glider, Bounds::new(0..=9, 0..=9), Topology::Plane);
+        let initial = world.live_cells().clone();
+        (0..40).for_each(|_| world.next_generation());
+        assert_ne!(world.live_cells(), &initial);
+    }
+
+    #[test]
+    fn should_join_neighbours_across_opposite_edges() {
+        let mut world = World::from(Cells::from_iter([
+            Cell::new(0, 4),
+            Cell::new(0, 0),
+            Cell::new(0, 1),
+        ]));
+        world
+            .with_bounds(&Bounds::new(-2..=2, 0..=4))
+            .expect("bounds suit the topology");
+        world
+            .with_topology(&Topology::Torus)
+            .expect("compatible topology");
+        world.next_generation();
+        let expected = Cells::from_iter([Cell::new(-1, 0), Cell::new(0, 0), Cell::new(1, 0)]);
+        assert_eq!(world.live_cells(), &expected);
+    }
+
+    #[test]
+    fn should_reverse_twisted_edges_of_a_klein_bottle() {
+        let bounds = Bounds::new(0..=4, 0..=9);
+        let klein = |twisted, shift| Topology::KleinBottle { twisted, shift };
+        let wrap =
+            |topology: Topology, row, column| topology.wrap(&bounds, &Cell::new(row, column));
+
+        assert_eq!(
+            wrap(klein(Edges::Horizontal, 0), -1, 2),
+            Some(Cell::new(4, 7))
+        );
+        assert_eq!(
+            wrap(klein(Edges::Horizontal, 1), -1, 2),
+            Some(Cell::new(4, 8))
+        );
+        assert_eq!(
+            wrap(klein(Edges::Horizontal, 0), 1, -1),
+            Some(Cell::new(1, 9))
+        );
+        assert_eq!(
+            wrap(klein(Edges::Vertical, 0), 1, -1),
+            Some(Cell::new(3, 9))
+        );
+        assert_eq!(wrap(klein(Edges::Vertical, 0), 5, 3), Some(Cell::new(0, 3)));
+    }
+
+    #[test]
+    fn should_reverse_both_pairs_of_edges_of_a_cross_surface() {
+        let bounds = Bounds::new(0..=4, 0..=9);
+        let wrap = |row, column| Topology::CrossSurface.wrap(&bounds, &Cell::new(row, column));
+        assert_eq!(wrap(-1, 2), Some(Cell::new(4, 7)));
+        assert_eq!(wrap(1, 10), Some(Cell::new(3, 0)));
+    }
+
+    #[test]
+    fn should_join_adjacent_edges_of_a_sphere() {
+        let bounds = Bounds::new(0..=4, 0..=4);
+        let wrap = |row, column| Topology::Sphere.wrap(&bounds, &Cell::new(row, column));
+        assert_eq!(wrap(-1, 2), Some(Cell::new(2, 0)));
+        assert_eq!(wrap(2, -1), Some(Cell::new(0, 2)));
+        assert_eq!(wrap(5, 1), Some(Cell::new(1, 4)));
+        assert_eq!(wrap(3, 5), Some(Cell::new(4, 3)));
+        assert_eq!(wrap(-1, -1), None);
+        assert!(Topology::Sphere.is_valid_for(&bounds));
+        assert!(!Topology::Sphere.is_valid_for(&Bounds::new(0..=4, 0..=9)));
+    }
+
+    #[test]
+    fn should_not_join_oblong_bounds_as_a_sphere() {
+        let mut world = World::try_from(&Pattern::Glider).expect("valid pattern");
+        world
+            .with_bounds(&Bounds::new(0..=4, 0..=9))
+            .expect("bounds suit the topology");
+        let error = world.with_topology(&Topology::Sphere).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "cannot join the edges of the bounds as a sphere"
+        );
+        assert_eq!(world.topology(), &Topology::Plane);
+    }
+
+    #[test]
+    fn should_not_bound_a_sphere_oblong() {
+        let mut world = World::try_from(&Pattern::Glider).expect("valid pattern");
+        world
+            .with_topology(&Topology::Sphere)
+            .expect("compatible topology");
+        let error = world.with_bounds(&Bounds::new(0..=9, 0..=19)).unwrap_err();
+        assert!(matches!(
+            error,
+            WorldError::IncompatibleTopology(Topology::Sphere)
+        ));
+        assert!(!world.bounds().is_defined());
+    }
+
+    #[test]
+    fn should_keep_a_glider_alive_on_every_surface() {
+        let topologies = [
+            Topology::Torus,
+            "klein-bottle".parse().expect("valid topology"),
+            Topology::CrossSurface,
+            Topology::Sphere,
+        ];
+        for topology in topologies {
+            let mut world = world(&Pattern::Glider, Bounds::new(-10..=9, -10..=9), topology);
+            (0..20).for_each(|_| world.next_generation());
+            assert_eq!(world.live_cells().len(), 5, "{:?}", topology);
+        }
+    }
+
+    #[test]
+    fn should_read_and_write_every_suffix() {
+        for grid in ["P20,10", "T20,10", "K20*,10", "K20,10*+1", "C20,10", "S20"] {
+            let text = format!("x = 3, y = 1, rule = B3/S23:{}\n3o!\n", grid);
+            let rle = Rle::try_from(text.as_str()).expect("valid rle");
+            assert_eq!(rle.to_string(), text);
+        }
+        assert!(Rle::try_from("x = 3, y = 1, rule = B3/S23:S20,10\n3o!").is_err());
+        assert!(Rle::try_from("x = 3, y = 1, rule = B3/S23:K20*,10*\n3o!").is_err());
+    }
+
+    #[test]
+    fn should_read_and_write_torus_suffix() {
+        let rle = Rle::try_from("x = 3, y = 1, rule = B3/S23:T20,10\n3o!").expect("valid rle");
+        assert_eq!(rle.topology(), &Topology::Torus);
+        assert_eq!(rle.bounds(), Some(&Bounds::new(-5..=4, -10..=9)));
+        assert!(rle.to_string().contains("rule = B3/S23:T20,10"));
+    }
+
+    #[test]
+    fn should_save_and_reload_a_klein_bottle_losslessly() {
+        let mut world = world(
+            &Pattern::GosperGliderGun,
+            Bounds::new(-20..=19, -25..=24),
+            Topology::KleinBottle {
+                twisted: Edges::Vertical,
+                shift: 1,
+            },
+        );
+        (0..60).for_each(|_| world.next_generation());
+
+        let path = std::env::temp_dir().join("life_topology_spec_klein_bottle.rle");
+        world.save(&path).expect("saved world");
+        let reloaded = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(reloaded, world);
+    }
+
+    #[test]
+    fn should_save_and_reload_a_torus_losslessly() {
+        let mut world = world(
+            &Pattern::GosperGliderGun,
+            Bounds::new(-20..=19, -25..=24),
+            Topology::Torus,
+        );
+        (0..60).for_each(|_| world.next_generation());
+
+        let path = std::env::temp_dir().join("life_topology_spec_torus.rle");
+        world.save(&path).expect("saved world");
+        let reloaded = World::try_from(path.as_path()).expect("valid world");
+        assert_eq!(reloaded, world);
+    }
+}
@@ -1,6 +1,7 @@
 mod world {
-    use life::prelude::{Bounds, Cell, Cells, Position, World};
+    use life::prelude::{Algorithm, Bounds, Cell, Cells, Pattern, Position, World};
     use pretty_assertions::assert_eq;
+    use rand::{RngExt, SeedableRng, rngs::StdRng};
 
     #[test]
     fn will_allow_an_initial_set_of_cells_to_be_provided() {
@@ -23,7 +24,9 @@ mod world {
         )
         .expect("valid cells");
         let mut world = World::from(cells);
-        world.with_bounds(&bounds);
+        world
+            .with_bounds(&bounds)
+            .expect("bounds suit the topology");
         assert_eq!(world.bounds(), &bounds);
     }
 
@@ -46,7 +49,9 @@ mod world {
         )
         .expect("valid cells");
         let mut world = World::from(initial_cells);
-        world.with_bounds(&bounds);
+        world
+            .with_bounds(&bounds)
+            .expect("bounds suit the topology");
         assert_eq!(world.live_cells(), &expected_cells);
     }
 
@@ -55,8 +60,12 @@ mod world {
         let initial_bounds = Bounds::new(0..=9, 0..=9);
         let new_bounds = Bounds::new(0..=4, 0..=4);
         let mut world = World::from(Cells::default());
-        world.with_bounds(&initial_bounds);
-        world.with_bounds(&new_bounds);
+        world
+            .with_bounds(&initial_bounds)
+            .expect("bounds suit the topology");
+        world
+            .with_bounds(&new_bounds)
+            .expect("bounds suit the topology");
         assert_eq!(world.bounds(), &new_bounds);
     }
 
@@ -121,7 +130,9 @@ mod world {
         .expect("valid cells");
 
         let mut world = World::from(initial_cells);
-        world.with_bounds(&bounds);
+        world
+            .with_bounds(&bounds)
+            .expect("bounds suit the topology");
         world.add_cells(additional_cells, &Position::new(1, 3));
         assert_eq!(world.live_cells(), &expected_cells);
     }
@@ -141,6 +152,12 @@ mod world {
         assert_eq!(world.to_string(), "*   *".to_string());
     }
 
+    #[test]
+    fn will_be_pretty_printed_around_its_cells_without_the_origin() {
+        let world = World::from(Cells::from_iter([Cell::new(5, 7), Cell::new(6, 9)]));
+        assert_eq!(world.to_string(), "*    \n    *");
+    }
+
     #[test]
     fn will_be_pretty_printable_when_viewport_defined() {
         let initial_cells = Cells::try_from(
@@ -165,4 +182,74 @@ mod world {
         .replace("\r", "");
         assert_eq!(world.to_string(), expected);
     }
+
+    #[test]
+    fn will_step_identically_whatever_the_number_of_threads() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let cells = Cells::from_iter(
+            (-60..60)
+                .flat_map(|r| (-20..20).map(move |c| Cell::new(r, c)))
+                .filter(|_| rng.random::<f32>() < 0.4),
+        );
+        let mut initial_world = World::from(cells);
+        initial_world.with_algorithm(&Algorithm::Sets);
+
+        let worlds = [1, 4].map(|threads| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .expect("thread pool");
+            let mut world = initial_world.clone();
+            pool.install(|| (0..20).for_each(|_| world.next_generation()));
+            world
+        });
+        assert_eq!(worlds[0], worlds[1]);
+    }
+
+    #[test]
+    fn will_carry_cells_between_bands_of_rows() {
+        let glider = Cells::try_from(Pattern::Glider.cells_str()).expect("valid pattern");
+        let mut world = World::from(glider.clone());
+        (0..4).for_each(|_| world.next_generation());
+        let step = world.live_cells().bounds();
+        let (dr, dc) = (
+            step.rows().start() - glider.bounds().rows().start(),
+            step.columns().start() - glider.bounds().columns().start(),
+        );
+
+        (4..4 * 40).for_each(|_| world.next_generation());
+        let expected =
+            Cells::from_iter(glider.iter().map(|c| *c + Position::new(40 * dr, 40 * dc)));
+        assert_eq!(world.live_cells(), &expected);
+    }
+
+    #[test]
+    fn will_step_many_generations_whatever_the_algorithm() {
+        let initial_world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        let mut expected = initial_world.clone();
+        (0..300).for_each(|_| expected.next_generation());
+
+        for algorithm in [Algorithm::Sets, Algorithm::HashLife, Algorithm::Tiles] {
+            let mut world = initial_world.clone();
+            world.with_algorithm(&algorithm);
+            world.step_n(300);
+            assert_eq!(world, expected, "{:?}", algorithm);
+        }
+    }
+
+    #[test]
+    fn will_lose_cells_leaving_a_bounded_plane_whatever_the_algorithm() {
+        let mut initial_world = World::try_from(&Pattern::GosperGliderGun).expect("valid pattern");
+        initial_world
+            .with_bounds(&Bounds::new(-5..=40, -5..=70))
+            .expect("bounds suit the topology");
+        let mut expected = initial_world.clone();
+        (0..200).for_each(|_| expected.next_generation());
+
+        let mut world = initial_world.clone();
+        world.with_algorithm(&Algorithm::Tiles);
+        (0..100).for_each(|_| world.next_generation());
+        world.step_n(100);
+        assert_eq!(world, expected);
+    }
 }